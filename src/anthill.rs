@@ -1,15 +1,28 @@
 /*! **Logic module** - ants managment and logic. */
 
 use {
+    sqds_tools::select,
     core::{
         fmt::Write,
         iter::repeat_with
         },
+    serde::{
+        Deserialize,
+        Serialize
+        },
+    std::collections::BTreeMap,
     crate::{
         error::NoFoodSourceError,
+        observer::Observer,
         tech::{
+            Algorithm,
+            BestRoute,
             Config,
-            Id
+            Deposit,
+            Id,
+            Problem,
+            Reinforcement,
+            Route
             },
         utils::Ant,
         world::World
@@ -18,6 +31,25 @@ use {
 
 
 
+/** **Technical part** - structure for holding the changing part of the colony, for saving, and restoring it. */
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AntHillState {
+    /** The ants, with their routes. */
+    ants: Box<[Ant]>,
+    /** Number of the current cycle. */
+    cycle: usize,
+    /** Number of cycles since the best route was last improved, or the trails were reset. */
+    stagnant_cycles: usize,
+    /** Number of cycles since the best route was last improved, counted once any route was found. */
+    unimproved_cycles: usize,
+    /** Possible shortest route found so far. */
+    best_route: Option<BestRoute>,
+    /** Possible route with the fewest hops found so far. */
+    fewest_hops: Option<BestRoute>,
+    /** Number of times each distinct route was finished. */
+    route_histogram: BTreeMap<Route, usize>
+    }
+
 /** `Anthill` strucutre, for handling `Ant`'s operations. */
 #[derive(Debug, Clone)]
 pub struct AntHill {
@@ -27,12 +59,48 @@ pub struct AntHill {
     ants: Box<[Ant]>,
     /** Anthill's ID. */
     anthill_id: Id,
+    /** IDs excluded from the covering. */
+    excluded: Box<[Id]>,
     /** Amount of pheromones laid out by ants. */
     pheromone: f64,
+    /** Method of calculating amount of deposited pheromones. */
+    deposit_method: Deposit,
     /** Amount of food ants cosume. */
     consume_rate: u32,
     /** Whether ants return to the colony after finishing their routes. */
-    do_return: bool
+    do_return: bool,
+    /** Variant of the algorithm. */
+    algorithm: Algorithm,
+    /** Route reinforced at the end of each cycle, for the MMAS. */
+    reinforcement: Reinforcement,
+    /** Number of cycles without improvement, after which trails are reset, for the MMAS. */
+    stagnation: usize,
+    /** Number of cycles since the best route was last improved, or the trails were reset. */
+    stagnant_cycles: usize,
+    /** Number of cycles since the best route was last improved, counted once any route was found. */
+    unimproved_cycles: usize,
+    /** Weight of the best-so-far route's additional cover, for the elitist variant. */
+    elite_weight: f64,
+    /** Number of top ants covering their routes, for the rank-based variant. */
+    rank_count: usize,
+    /** Number of the current cycle, counted from 1. */
+    cycle: usize,
+    /** Possible shortest route found so far, in metric units. */
+    best_route: Option<BestRoute>,
+    /** Possible route with the fewest hops found so far. */
+    fewest_hops: Option<BestRoute>,
+    /** Number of times each distinct route was finished. */
+    route_histogram: BTreeMap<Route, usize>,
+    /** Routes finished in the current cycle, with their lengths in metric units - collected only if the deposit is deferred. */
+    finished: Vec<(Route, f64)>,
+    /** Number of routes finished in the current cycle. */
+    cycle_finished: usize,
+    /** Total length of routes finished in the current cycle. */
+    cycle_length_sum: f64,
+    /** Total number of points of routes finished in the current cycle. */
+    cycle_points_sum: usize,
+    /** Shortest length of routes finished in the current cycle. */
+    cycle_best_length: f64
     }
 
 impl AntHill {
@@ -42,39 +110,98 @@ impl AntHill {
             .take(config.ants)
             .collect();
 
+        /* Tours end at the anthill, so it's covered too */
+        let excluded = match config.problem {
+            Problem::Forage => Box::from([anthill_id]),
+            Problem::Tour => Box::default()
+            };
+
         /* Create anthill */
         Self {
             num_of_ants: config.ants,
             ants,
             anthill_id,
+            excluded,
             pheromone: config.pheromone,
+            deposit_method: config.deposit,
             consume_rate: config.rate,
-            do_return: config.returns
+            do_return: config.returns,
+            algorithm: config.algorithm,
+            reinforcement: config.reinforcement,
+            stagnation: config.stagnation,
+            stagnant_cycles: 0,
+            unimproved_cycles: 0,
+            elite_weight: config.elite_weight,
+            rank_count: config.rank_count,
+            cycle: 0,
+            best_route: None,
+            fewest_hops: None,
+            route_histogram: BTreeMap::new(),
+            finished: Vec::new(),
+            cycle_finished: 0,
+            cycle_length_sum: 0.0,
+            cycle_points_sum: 0,
+            cycle_best_length: f64::INFINITY
             }
         }
 
-    /** Make all unsatiated ants take action. */
-    pub fn action(&mut self, world: &mut World) -> Result<(), NoFoodSourceError> {
+    /** Make all unsatiated ants take action, notifying the observer about their moves. */
+    pub fn action<O>(&mut self, world: &mut World, observer: &mut O) -> Result<(), NoFoodSourceError>
+    where O: Observer + ?Sized {
         /* Precalculate condition */
         let do_ants_cosume = self.consume_rate != 0;
 
+        /* Forget the previous cycle's routes, and start the new one */
+        self.cycle += 1;
+        self.finished.clear();
+        self.cycle_finished = 0;
+        self.cycle_length_sum = 0.0;
+        self.cycle_points_sum = 0;
+        self.cycle_best_length = f64::INFINITY;
+        let mut improved = false;
+
         /* Iter over unsatiated ants */
-        for ant in self.ants.iter_mut().filter(|ant| ! ant.satiated) {
-            /* Get new position, and check if it's a food source */
+        for (number, ant) in self.ants.iter_mut().enumerate().filter(|(_, ant)| ! ant.satiated) {
+            /* Get new position, and check if it's the goal */
             let new_position = world.get_new_position(&ant.route)?;
-            let food_reached = world.is_food_source(&new_position);
+            let goal_reached = world.is_goal(&new_position, &self.anthill_id);
         
             /* Update current position, and path */
             ant.route.push(new_position);
+            world.local_update(&ant.route);
+            observer.on_ant_move(number, &ant.route);
         
-            /* Actions taken upon reaching a food source */
-            if food_reached {
-                /* Mark ant as satiated, and cover the route */
+            /* Actions taken upon reaching the goal */
+            if goal_reached {
+                /* Mark ant as satiated, and cover the route, if applicable */
                 ant.satiated = true;
-                world.cover_route(&ant.route, &[self.anthill_id], self.pheromone);
+                if self.algorithm == Algorithm::Generic {
+                    let deposit = self.deposit_method.calculate(self.pheromone, &ant.route, world);
+                    world.cover_route(&ant.route, &self.excluded, deposit);
+                    }
+
+                /* Remember the route, if it's the shortest so far, and collect it for the deferred deposit, if applicable */
+                let length = world.route_length(&ant.route);
+                observer.on_food_reached(number, &ant.route, length);
+                improved |= keep_best(&mut self.best_route, &ant.route, length, self.cycle, |best| length < best.length());
+                keep_best(&mut self.fewest_hops, &ant.route, length, self.cycle, |best| ant.route.hops() < best.hops());
+                if let Some(count) = self.route_histogram.get_mut(&ant.route) {
+                    *count += 1;
+                } else {
+                    self.route_histogram.insert(ant.route.clone(), 1);
+                    }
+                if self.algorithm.defers_deposit() {
+                    self.finished.push((ant.route.clone(), length));
+                    }
+
+                /* Count the route's length */
+                self.cycle_finished += 1;
+                self.cycle_length_sum += length;
+                self.cycle_points_sum += ant.route.len();
+                self.cycle_best_length = self.cycle_best_length.min(length);
 
                 /* If ants consume, consume the food source */
-                if do_ants_cosume {
+                if do_ants_cosume && world.is_foraging() {
                     world.consume_food_source(new_position, self.consume_rate);
                     }
 
@@ -86,14 +213,110 @@ impl AntHill {
                 }
             }
 
+        /* Count cycles without improvement, once any route was found */
+        self.unimproved_cycles = select!(improved || self.best_route.is_none(), 0, self.unimproved_cycles + 1);
+
+        /* Update trails at the end of the cycle */
+        self.update_trails(world, improved);
+
         Ok(())
         }
 
-    /** Reset all ants. */
+    /** Make the end of cycle pheromone updates, according to the algorithm. */
+    fn update_trails(&mut self, world: &mut World, improved: bool) {
+        match self.algorithm {
+            Algorithm::Generic => (),
+            /* Global update along the best-so-far route */
+            Algorithm::Acs => if let Some(best) = &self.best_route {
+                let route = best.route();
+                let deposit = self.deposit_method.calculate(self.pheromone, route, world);
+                world.reinforce_route(route, &self.excluded, deposit);
+                },
+            Algorithm::Mmas => {
+                /* Cover only the best route */
+                let best = match self.reinforcement {
+                    Reinforcement::Iteration => self.finished.iter()
+                        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                        .map(|(route, _)| route),
+                    Reinforcement::Global => self.best_route.as_ref()
+                        .map(BestRoute::route)
+                    };
+                if let Some(route) = best {
+                    let deposit = self.deposit_method.calculate(self.pheromone, route, world);
+                    world.cover_route(route, &self.excluded, deposit);
+                    }
+
+                /* Count cycles without improvement, and reset trails upon stagnation */
+                self.stagnant_cycles = select!(improved, 0, self.stagnant_cycles + 1);
+                if self.stagnation != 0 && self.stagnant_cycles >= self.stagnation {
+                    world.reset_trails();
+                    self.stagnant_cycles = 0;
+                    }
+                },
+            Algorithm::Elitist => {
+                /* Cover all routes of the cycle */
+                for (route, _) in &self.finished {
+                    let deposit = self.deposit_method.calculate(self.pheromone, route, world);
+                    world.cover_route(route, &self.excluded, deposit);
+                    }
+
+                /* Additionally cover the best-so-far route */
+                if let Some(best) = &self.best_route {
+                    let route = best.route();
+                    let deposit = self.deposit_method.calculate(self.pheromone, route, world);
+                    world.cover_route(route, &self.excluded, self.elite_weight * deposit);
+                    }
+                },
+            Algorithm::Rank => {
+                /* Cover only the top routes of the cycle, weighted by their rank */
+                self.finished.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+                for (rank, (route, _)) in self.finished.iter().take(self.rank_count).enumerate() {
+                    let weight = (self.rank_count - rank) as f64;
+                    let deposit = self.deposit_method.calculate(self.pheromone, route, world);
+                    world.cover_route(route, &self.excluded, weight * deposit);
+                    }
+                }
+            }
+        }
+
+    /** Reset all ants, and forget the best routes. */
     pub fn reset(&mut self) {
         for ant in &mut self.ants {
             ant.reset(self.anthill_id);
             }
+        self.stagnant_cycles = 0;
+        self.unimproved_cycles = 0;
+        self.cycle = 0;
+        self.best_route = None;
+        self.fewest_hops = None;
+        self.route_histogram.clear();
+        self.finished.clear();
+        }
+
+    /** Take the changing part of the colony, between the cycles. */
+    pub fn state(&self) -> AntHillState {
+        AntHillState {
+            ants: self.ants.clone(),
+            cycle: self.cycle,
+            stagnant_cycles: self.stagnant_cycles,
+            unimproved_cycles: self.unimproved_cycles,
+            best_route: self.best_route.clone(),
+            fewest_hops: self.fewest_hops.clone(),
+            route_histogram: self.route_histogram.clone()
+            }
+        }
+
+    /** Restore the changing part of the colony, taken from the colony of the same size. */
+    pub fn restore(&mut self, state: AntHillState) {
+        let AntHillState { ants, cycle, stagnant_cycles, unimproved_cycles, best_route, fewest_hops, route_histogram } = state;
+
+        self.ants = ants;
+        self.cycle = cycle;
+        self.stagnant_cycles = stagnant_cycles;
+        self.unimproved_cycles = unimproved_cycles;
+        self.best_route = best_route;
+        self.fewest_hops = fewest_hops;
+        self.route_histogram = route_histogram;
         }
 
     /** Show a table of states of all ants. */
@@ -127,11 +350,40 @@ impl AntHill {
     /** `average_routes_count` getter. */
     pub fn average_routes_count(&self) -> f64 {
         let sum: usize = self.ants.iter()
-            .map(|ant| ant.routes_counter as usize)
+            .map(|ant| ant.routes_counter)
             .sum();
 
         sum as f64 / self.num_of_ants as f64
         }
+    /** `best_route` getter. */
+    #[inline]
+    pub const fn best_route(&self) -> Option<&BestRoute>
+        { self.best_route.as_ref() }
+    /** `fewest_hops` getter. */
+    #[inline]
+    pub const fn fewest_hops(&self) -> Option<&BestRoute>
+        { self.fewest_hops.as_ref() }
+    /** `unimproved_cycles` getter. */
+    #[inline]
+    pub const fn unimproved_cycles(&self) -> usize
+        { self.unimproved_cycles }
+    /** `route_histogram` getter. */
+    #[inline]
+    pub const fn route_histogram(&self) -> &BTreeMap<Route, usize>
+        { &self.route_histogram }
+    /** Shortest, and average length of routes finished in the last cycle, if any were finished. */
+    pub fn cycle_route_lengths(&self) -> (Option<f64>, Option<f64>) {
+        if self.cycle_finished == 0 {
+            return (None, None);
+            }
+
+        (Some(self.cycle_best_length), Some(self.cycle_length_sum / self.cycle_finished as f64))
+        }
+    /** Average number of points of routes finished in the last cycle, if any were finished. */
+    pub fn cycle_average_route_length(&self) -> Option<f64> {
+        (self.cycle_finished != 0)
+            .then(|| self.cycle_points_sum as f64 / self.cycle_finished as f64)
+        }
     /** `satiated_ants_count` getter. */
     pub fn satiated_ants_count(&self) -> usize {
         self.ants.iter()
@@ -143,4 +395,83 @@ impl AntHill {
         self.ants.iter()
             .all(|ant| ant.satiated)
         }
-    }
\ No newline at end of file
+    }
+
+/** **Technical part** - helper function for replacing the remembered route, if the new one is better, or counting the ant, if it's the same route. */
+fn keep_best<F>(best: &mut Option<BestRoute>, route: &Route, length: f64, cycle: usize, is_better: F) -> bool
+where F: Fn(&BestRoute) -> bool {
+    match best {
+        Some(current) if current.route() == route => {
+            current.count_ant();
+            false
+            },
+        Some(current) if ! is_better(current) =>
+            false,
+        _ => {
+            *best = Some(BestRoute::new(route.clone(), length, cycle));
+            true
+            }
+        }
+    }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::consts::default::GRID
+        };
+
+    /* Builds the route from the points' IDs */
+    fn route(ids: &str) -> Route {
+        let mut route = Route::new();
+        for id in ids.chars() {
+            route.push(Id::new(id));
+            }
+        route
+        }
+
+    #[test]
+    fn mmas_stagnation_resets_trails() {
+        let config = Config {
+            algorithm: Algorithm::Mmas,
+            reinforcement: Reinforcement::Iteration,
+            stagnation: 2,
+            tau_min: 0.1,
+            tau_max: 2.0,
+            ..Config::default()
+            };
+        let mut world = World::new(Vec::from(GRID), &config);
+        let mut ant_hill = AntHill::new(Id::new('a'), &config, world.number_of_points());
+        let initial = world.pheromones_per_point();
+
+        /* The first unimproved cycle only covers the cycle's best route */
+        ant_hill.finished.push((route("acg"), 9.0));
+        ant_hill.update_trails(&mut world, false);
+        assert_eq!(ant_hill.stagnant_cycles, 1);
+        assert_ne!(world.pheromones_per_point(), initial);
+
+        /* The second one reaches the stagnation, and resets the trails */
+        ant_hill.update_trails(&mut world, false);
+        assert_eq!(ant_hill.stagnant_cycles, 0);
+        assert_eq!(world.pheromones_per_point(), initial);
+        }
+
+    #[test]
+    fn mmas_improvement_restarts_stagnation() {
+        let config = Config {
+            algorithm: Algorithm::Mmas,
+            stagnation: 2,
+            tau_min: 0.1,
+            tau_max: 2.0,
+            ..Config::default()
+            };
+        let mut world = World::new(Vec::from(GRID), &config);
+        let mut ant_hill = AntHill::new(Id::new('a'), &config, world.number_of_points());
+
+        ant_hill.update_trails(&mut world, false);
+        ant_hill.update_trails(&mut world, true);
+        ant_hill.update_trails(&mut world, false);
+
+        assert_eq!(ant_hill.stagnant_cycles, 1);
+        }
+    }
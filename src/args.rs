@@ -2,18 +2,47 @@
 
 use {
     clap::{
+        parser::ValueSource,
         value_parser,
-        Parser
+        CommandFactory,
+        FromArgMatches,
+        Parser,
+        Subcommand
+        },
+    core::fmt::Write,
+    serde_json::{
+        to_value,
+        Value
         },
     std::path::PathBuf,
-    crate::{
+    ant_algorithm::{
         consts::{
             bias,
             default::*,
             limits::*
             },
-        tech::*,
-        utils::Point
+        error::{
+            ScenarioError,
+            SweepError
+            },
+        Action,
+        Algorithm,
+        Checkpointing,
+        Config,
+        Deposit,
+        Dispersion,
+        DisjointConfig,
+        Id,
+        Metric,
+        Objective,
+        Parameter,
+        PheromoneModel,
+        Point,
+        Preference,
+        Problem,
+        Reinforcement,
+        Scenario,
+        Selection
         }
     };
 
@@ -23,6 +52,10 @@ use {
 #[derive(Debug, Clone, Parser)]
 #[command(author, version, about)]
 pub struct Args {
+    /// Runs the subcommand, instead of a single simulation - the other arguments set it's base configuration.
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Sets number of cycles.
     #[arg(short, long, default_value_t = NUM_OF_CYCLES, value_parser = value_parser!(u64).range(CYCLES_RANGE))]
     cycles: u64,
@@ -42,6 +75,15 @@ pub struct Args {
     /// Sets whether ants return to the anthill.
     #[arg(short = 'R', long, action, default_value_t = RETURN_BEHAVIOUR)]
     returns: bool,
+    /// Sets the problem solved by the ants.
+    #[arg(long, value_enum, default_value_t = PROBLEM)]
+    problem: Problem,
+    /// Sets the target point, ants are satiated only upon reaching it.
+    ///
+    /// Food sources stop being the goal, and aren't consumed.
+    /// Can't be the anthill, or used with tours.
+    #[arg(long, verbatim_doc_comment)]
+    target: Option<Id>,
 
     /// Sets how points are selected.
     #[arg(short = 'S', long, value_enum, default_value_t = SELECT_METHOD)]
@@ -52,9 +94,18 @@ pub struct Args {
     /// Sets how the distance between points is calculated.
     #[arg(short = 'M', long, value_enum, default_value_t = METRIC)]
     metric: Metric,
+    /// Sets the exponent of the pheromone factor in the point preference.
+    #[arg(long, default_value_t = EXPONENT)]
+    alpha: f64,
+    /// Sets the exponent of the distance factor in the point preference.
+    #[arg(long, default_value_t = EXPONENT)]
+    beta: f64,
+    /// Sets the exponent of the food factor in the point preference.
+    #[arg(long, default_value_t = EXPONENT)]
+    gamma: f64,
 
     /// Sets the dispersion mode.
-    #[arg(short = 'D', long, value_enum, requires = "factor")]
+    #[arg(short = 'D', long, value_enum)]
     dispersion: Option<Dispersion>,
     /// Sets the coefficient of the dispersion.
     ///
@@ -62,8 +113,61 @@ pub struct Args {
     /// - linear      - 0 <= factor
     /// - exponential - 1 <= factor
     /// - relative    - 0 <= factor <= 1
-    #[arg(short = 'f', long, requires = "dispersion", verbatim_doc_comment)]
+    #[arg(short = 'f', long, verbatim_doc_comment)]
     factor: Option<f64>,
+    /// Sets where pheromones are laid out.
+    #[arg(long, value_enum, default_value_t = PHEROMONE_MODEL)]
+    pheromone_model: PheromoneModel,
+    /// Sets how the amount of deposited pheromones is calculated.
+    #[arg(long, value_enum, default_value_t = DEPOSIT_METHOD)]
+    deposit: Deposit,
+
+    /// Sets the variant of the algorithm.
+    ///
+    /// The ACS requires the relative dispersion, the factor is used for the global update, and the proportional selection.
+    #[arg(long, value_enum, default_value_t = ALGORITHM, verbatim_doc_comment)]
+    algorithm: Algorithm,
+    /// Sets the probability of the greedy choice, for the proportional selection.
+    #[arg(long, default_value_t = GREEDY_PROBABILITY)]
+    q0: f64,
+    /// Sets the local pheromone update coefficient, for the ACS.
+    #[arg(long, default_value_t = LOCAL_UPDATE_COEFFICIENT)]
+    xi: f64,
+    /// Sets the initial amount of pheromones, for the ACS.
+    #[arg(long, default_value_t = INITIAL_PHEROMONE)]
+    tau0: f64,
+    /// Sets the lower limit of pheromones on a trail, for the MMAS.
+    #[arg(long, default_value_t = TRAIL_MIN)]
+    tau_min: f64,
+    /// Sets the upper limit, and initial amount of pheromones on a trail, for the MMAS.
+    #[arg(long, default_value_t = TRAIL_MAX)]
+    tau_max: f64,
+    /// Sets the route reinforced at the end of each cycle, for the MMAS.
+    #[arg(long, value_enum, default_value_t = REINFORCEMENT)]
+    reinforcement: Reinforcement,
+    /// Sets the number of cycles without improvement, after which trails are reset, for the MMAS.
+    ///
+    /// Zero disables the reset.
+    #[arg(long, default_value_t = STAGNATION_CYCLES, verbatim_doc_comment)]
+    stagnation: usize,
+    /// Sets the weight of the best-so-far route's additional cover, for the elitist variant.
+    #[arg(long, default_value_t = ELITE_WEIGHT)]
+    elite_weight: f64,
+    /// Sets the number of top ants covering their routes, for the rank-based variant.
+    #[arg(long, default_value_t = RANK_COUNT)]
+    rank_count: usize,
+
+    /// Stops the run, once all ants are satiated.
+    #[arg(long, action, default_value_t = STOP_SATIATED)]
+    stop_satiated: bool,
+    /// Stops the run, once the best route wasn't improved for the number of cycles, counted after the first route was found.
+    #[arg(long)]
+    patience: Option<usize>,
+    /// Stops the run, once the relative entropy of pheromones drops below the threshold.
+    ///
+    /// The entropy is relative to the uniform spread over the points other than the anthill, or the edges between distinct points - 0 <= entropy <= 1.
+    #[arg(long, verbatim_doc_comment)]
+    entropy: Option<f64>,
     
     /// Sets new world grid.
     ///
@@ -80,9 +184,48 @@ pub struct Args {
     #[arg(short = 'A', long, verbatim_doc_comment)]
     actions: Option<Vec<Action>>,
 
+    /// A scenario file to load the grid, actions, and settings from.
+    ///
+    /// Format is chosen by the extension - `.toml`, or `.json`.
+    /// Arguments passed in the command line override the file's values.
+    #[arg(long, verbatim_doc_comment)]
+    scenario: Option<PathBuf>,
+
+    /// A file to save the simulation's state to, for resuming it later.
+    ///
+    /// Will create, or truncate existing file, and remove it once the batch is finished.
+    /// Searches path from current working directory.
+    #[arg(long, verbatim_doc_comment)]
+    checkpoint: Option<PathBuf>,
+    /// Sets the number of cycles between the checkpoints.
+    ///
+    /// Zero disables saving between the cycles.
+    #[arg(long, requires = "checkpoint", default_value_t = CHECKPOINT_CYCLES, verbatim_doc_comment)]
+    checkpoint_cycles: usize,
+    /// Sets the number of finished runs between the checkpoints.
+    ///
+    /// Zero disables saving between the runs.
+    #[arg(long, requires = "checkpoint", default_value_t = CHECKPOINT_RUNS, verbatim_doc_comment)]
+    checkpoint_runs: usize,
+    /// A checkpoint file to resume the simulation from.
+    ///
+    /// The simulation's settings are taken from the file, other than the checkpoints' ones, and the number of threads.
+    /// Can't be used with any other simulation's settings.
+    #[arg(long, verbatim_doc_comment, conflicts_with_all = [
+        "cycles", "ants", "pheromone", "decision", "rate", "returns", "problem", "target",
+        "select", "preference", "metric", "alpha", "beta", "gamma", "dispersion", "factor",
+        "pheromone_model", "deposit", "algorithm", "q0", "xi", "tau0", "tau_min", "tau_max",
+        "reinforcement", "stagnation", "elite_weight", "rank_count", "stop_satiated", "patience", "entropy",
+        "grid", "actions", "scenario", "quiet", "series", "seed", "batch", "run_index"
+        ])]
+    resume: Option<PathBuf>,
+
     /// Run program in quite mode.
     #[arg(short, long, action, default_value_t = QUIET)]
     quiet: bool,
+    /// Record per-cycle series of pheromones, food, and route lengths in the statistics.
+    #[arg(long, action, default_value_t = SERIES)]
+    series: bool,
     /// Run program with a seed.
     #[arg(short, long)]
     seed: Option<u64>,
@@ -92,12 +235,215 @@ pub struct Args {
     /// Sets how many times to run the simulation.
     #[arg(short, long, default_value_t = BATCH_SIZE, value_parser = value_parser!(u64).range(BATCH_RANGE))]
     batch: u64,
+    /// Sets how many threads run the simulation's repetitions.
+    ///
+    /// The statistics don't depend on the number of threads.
+    /// Checkpoints between the cycles keep the repetitions on a single thread.
+    #[arg(short, long, default_value_t = JOBS, value_parser = value_parser!(u64).range(JOBS_RANGE), verbatim_doc_comment)]
+    jobs: u64,
+    /// Simulates only the run of the batch with the index, counted from 0, with full logging.
+    ///
+    /// With the batch's seed, reproduces exactly that run.
+    #[arg(long, verbatim_doc_comment)]
+    run_index: Option<usize>,
     /// A file to write statistics to in JSON format.
     ///
     /// Will create, or append/truncate existing file.  
+    /// With the `.ndjson`, or `.jsonl` extension, appends a JSON line per run instead.  
     /// Searches path from current working directory.
     #[arg(short, long, verbatim_doc_comment)]
-    output: Option<PathBuf>
+    output: Option<PathBuf>,
+    /// A file to write averaged statistics, and their spread to in JSON format.
+    ///
+    /// Will create, or truncate existing file.
+    /// Searches path from current working directory.
+    #[arg(long, verbatim_doc_comment)]
+    summary: Option<PathBuf>
+    }
+
+impl Args {
+    /** Parses the CL arguments into config objects, filling the omitted ones from the scenario file, if passed. */
+    pub fn load() -> Result<(ActionsConfig, Config, DisjointConfig), ScenarioError> {
+        /* Parse the CL arguments, while keeping track of their sources */
+        let matches = Self::command().get_matches();
+        let args = Self::from_arg_matches(&matches)
+            .unwrap_or_else(|err| err.exit());
+
+        /* Break args into config structs */
+        let scenario_path = args.scenario.clone();
+        let (actions, mut config, mut disjoint) = args.into();
+
+        /* If passed, load the scenario file */
+        if let Some(path) = scenario_path.as_deref() {
+            let scenario = Scenario::from_file(path)?;
+
+            /* Drop the values overridden in the command line, the scenario's fields are named after the arguments */
+            let given = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+            let scenario = scenario.without(given)?;
+
+            /* Fill in the rest */
+            scenario.apply(&mut config, &mut disjoint);
+            }
+
+        Ok((actions, config, disjoint))
+        }
+    }
+
+/** **Technical part** - subcommands of the tool. */
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Runs a batch for every combination of the parameters' values, and shows one result table.
+    Sweep {
+        /// A file to write the result table to in JSON format.
+        ///
+        /// Will create, or truncate existing file.
+        /// Must be passed before the parameters.
+        #[arg(long, verbatim_doc_comment)]
+        table: Option<PathBuf>,
+        /// Parameters to sweep, as `--field values` pairs for any configuration's field.
+        ///
+        /// Values are an inclusive range `start..end:step`, or a comma separated list.
+        /// E.g. `--ants 10..100:10 --pheromone 0.5,1,2 --select roulette,greedy`.
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true, num_args = 1.., required = true, value_name = "PARAMETERS", verbatim_doc_comment)]
+        parameters: Vec<String>
+        },
+    /// Searches the parameters' values, which best meet the objective, and shows them as a command line.
+    ///
+    /// Uses successive halving - each round drops the worse half of the candidates, and doubles the runs of the rest.
+    #[command(verbatim_doc_comment)]
+    Tune {
+        /// Sets the objective to meet.
+        #[arg(long, value_enum, default_value_t = OBJECTIVE)]
+        objective: Objective,
+        /// Sets the cycle, by which the ants should be satiated, for the `satiated` objective.
+        ///
+        /// Defaults to the last cycle.
+        #[arg(long, verbatim_doc_comment)]
+        by_cycle: Option<usize>,
+        /// Sets the number of runs per candidate in the first round.
+        #[arg(long, default_value_t = TUNING_RUNS, value_parser = value_parser!(u64).range(BATCH_RANGE))]
+        runs: u64,
+        /// A scenario file to write the winning configuration to.
+        ///
+        /// Format is chosen by the extension - `.toml`, or `.json`.
+        /// Must be passed before the parameters.
+        #[arg(long, verbatim_doc_comment)]
+        write_scenario: Option<PathBuf>,
+        /// Parameters to tune, as `--field values` pairs for any configuration's field.
+        ///
+        /// Values are an inclusive range `start..end:step`, or a comma separated list.
+        /// E.g. `--pheromone 0.5..2:0.5 --decision 2,3 --select roulette,greedy`.
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true, num_args = 1.., required = true, value_name = "PARAMETERS", verbatim_doc_comment)]
+        parameters: Vec<String>
+        },
+    /// Compares two statistics' files metric by metric, with the Mann-Whitney U test.
+    ///
+    /// Shows the medians, the two-sided p-values from the normal approximation with the tie correction, and the rank-biserial effect sizes.
+    /// Positive effect size means the first file's values tend to be greater.
+    /// Runs without any route count as the worst ones for the best route's length, and the fewest hops.
+    #[command(verbatim_doc_comment)]
+    Compare {
+        /// The first statistics' file.
+        first: PathBuf,
+        /// The second statistics' file.
+        second: PathBuf
+        },
+    /// Converts a statistics' file between a JSON array, and JSON Lines.
+    ///
+    /// Formats are chosen by the extensions - `.ndjson`, and `.jsonl` for JSON Lines, JSON array otherwise.
+    /// Will create, or truncate the output file.
+    #[command(verbatim_doc_comment)]
+    Convert {
+        /// The statistics' file to read.
+        input: PathBuf,
+        /// The statistics' file to write.
+        output: PathBuf
+        },
+    /// Re-runs the saved runs from their manifests, with full logging, and checks whether the statistics are reproduced bit for bit.
+    ///
+    /// Fails on the first entry, which isn't reproduced.
+    #[command(verbatim_doc_comment)]
+    Replay {
+        /// The statistics' file, written with the manifests.
+        file: PathBuf,
+        /// Replays only the entry with the index, counted from 0.
+        ///
+        /// Defaults to all entries.
+        #[arg(long, verbatim_doc_comment)]
+        entry: Option<usize>
+        }
+    }
+
+impl Command {
+    /** Parses the `--field values` pairs into parameters. */
+    pub fn parse_parameters(parameters: &[String]) -> Result<Vec<Parameter>, SweepError> {
+        parameters.chunks(2)
+            .map(|pair| match pair {
+                [name, values] if name.starts_with("--") =>
+                    Parameter::new(name.trim_start_matches("--"), values),
+                [name, ..] if name.starts_with("--") =>
+                    Err(SweepError::MissingValues(name.trim_start_matches("--").to_owned())),
+                _ =>
+                    Err(SweepError::UnknownParameter(pair.join(" ")))
+                })
+            .collect()
+        }
+    }
+
+/** **Technical part** - builds a command line for the configs, with the arguments differing from the defaults. */
+pub fn command_line(config: &Config, disjoint_config: &DisjointConfig) -> String {
+    let mut line = String::from(env!("CARGO_PKG_NAME"));
+
+    /* Main config, compared by it's serialized fields */
+    let fields = |config: &Config| match to_value(config) {
+        Ok(Value::Object(fields)) => fields,
+        _ => unreachable!("The configuration should always serialize to an object")
+        };
+    let defaults = fields(&Config::default());
+    for (name, value) in fields(config) {
+        if defaults.get(&name) == Some(&value) {
+            continue;
+            }
+        let flag = name.replace('_', "-");
+        match value {
+            Value::Null | Value::Bool(false) => (),
+            Value::Bool(true) => _ = write!(line, " --{flag}"),
+            Value::String(string) => _ = write!(line, " --{flag} {string}"),
+            _ => _ = write!(line, " --{flag} {value}")
+            }
+        }
+
+    /* Grid, and actions */
+    let show_point = |Point { id, x, y, food_amount, .. }: &Point| match food_amount {
+        0 => format!("{id},{x},{y}"),
+        _ => format!("{id},{x},{y},{food_amount}")
+        };
+    let grid: Vec<_> = disjoint_config.grid.iter()
+        .map(show_point)
+        .collect();
+    if grid != GRID.iter().map(show_point).collect::<Vec<_>>() {
+        _ = write!(line, " --grid {}", grid.join(" "));
+        }
+    for Action { cycle, id, food_amount } in &disjoint_config.actions {
+        _ = write!(line, " --actions {cycle},{id},{food_amount}");
+        }
+
+    line
+    }
+
+/** **Technical part** - structure for grouping of simulation's configuration of outer actions. */
+#[derive(Debug, Clone)]
+pub struct ActionsConfig {
+    /** Whether simulation time should be displayed. */
+    pub counts_time: bool,
+    /** Possible path for the statistics' output file. */
+    pub output_path: Option<PathBuf>,
+    /** Possible path for the averaged statistics' output file. */
+    pub summary_path: Option<PathBuf>,
+    /** Possible path for the checkpoint file to resume from. */
+    pub resume_path: Option<PathBuf>,
+    /** Possible subcommand to run instead. */
+    pub command: Option<Command>
     }
 
 /** **Technical part** - trait implementation for unpacking CLI arguments into config objects. */
@@ -105,15 +451,23 @@ impl From<Args> for (ActionsConfig, Config, DisjointConfig) {
     fn from(value: Args) -> Self {
         /* Unpack arguments */
         let Args {
-            timing, output,
-            cycles, ants, pheromone, decision, rate, returns, select, preference, metric, dispersion, factor, seed,
-            grid, actions, quiet, batch
+            command, timing, output, summary, resume,
+            cycles, ants, pheromone, decision, rate, returns, problem, target,
+            select, preference, metric, alpha, beta, gamma,
+            dispersion, factor, pheromone_model, deposit,
+            algorithm, q0, xi, tau0, tau_min, tau_max, reinforcement, stagnation, elite_weight, rank_count,
+            stop_satiated, patience, entropy, seed,
+            grid, actions, quiet, series, batch, jobs, run_index,
+            checkpoint, checkpoint_cycles, checkpoint_runs, ..
             } = value;
 
         /* Set actions config */
         let actions_config = ActionsConfig {
             counts_time: timing,
-            output_path: output
+            output_path: output,
+            summary_path: summary,
+            resume_path: resume,
+            command
             };
 
         /* Set main config */
@@ -124,23 +478,67 @@ impl From<Args> for (ActionsConfig, Config, DisjointConfig) {
             decision: decision as usize,
             rate,
             returns,
+            problem,
+            target,
             select,
             preference,
             metric,
+            alpha,
+            beta,
+            gamma,
             dispersion,
             factor: factor.unwrap_or(bias::UNKOWN),
+            pheromone_model,
+            deposit,
+            algorithm,
+            q0,
+            xi,
+            tau0,
+            tau_min,
+            tau_max,
+            reinforcement,
+            stagnation,
+            elite_weight,
+            rank_count,
+            stop_satiated,
+            patience,
+            entropy,
             seed
             };
 
         /* Set disjoint config */
         let disjoint_config = DisjointConfig {
             no_logging: quiet,
+            records_series: series,
             batch_size: batch as usize,
+            jobs: jobs as usize,
+            run_index,
             grid: grid.unwrap_or_else(|| Vec::from(GRID)),
-            actions: actions.unwrap_or_default()
+            actions: actions.unwrap_or_default(),
+            checkpointing: checkpoint.map(|path| Checkpointing {
+                path,
+                cycles: checkpoint_cycles,
+                runs: checkpoint_runs
+                })
             };
 
         /* Create tuple with configs */
         (actions_config, config, disjoint_config)
         }
-    }
\ No newline at end of file
+    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scenario_fields_are_arguments() {
+        let Value::Object(fields) = to_value(Scenario::default()).unwrap() else {
+            panic!("The scenario should serialize to an object");
+            };
+        let command = Args::command();
+
+        for name in fields.keys() {
+            assert!(command.get_arguments().any(|arg| arg.get_id() == name.as_str()), "Field `{}` has no matching argument", name);
+            }
+        }
+    }
@@ -0,0 +1,255 @@
+/*! **Technical module** - statistical comparison of two sets of runs. */
+
+use {
+    serde::Serialize,
+    crate::{
+        tech::{
+            BestRoute,
+            Stats
+            },
+        utils::statistics::{
+            quantile,
+            ranks,
+            two_sided_p_value
+            }
+        }
+    };
+
+
+
+/** **Technical part** - extraction of a metric from the run's statistics, missing if the run lacks it. */
+type Metric = fn(&Stats) -> Option<f64>;
+
+/** **Technical part** - structure for holding the results of the Mann-Whitney U test, with the tie correction, and the normal approximation. */
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MannWhitney {
+    /** U statistic of the first sample. */
+    u: f64,
+    /** Standard normal statistic, with the continuity correction. */
+    z: f64,
+    /** Two-sided p-value. */
+    p_value: f64,
+    /** Rank-biserial correlation - positive, if the first sample's values tend to be greater. */
+    effect_size: f64
+    }
+
+impl MannWhitney {
+    /** Constructor, runs the test on two samples, which can't be empty. */
+    pub fn new(a: &[f64], b: &[f64]) -> Self {
+        let (n1, n2) = (a.len() as f64, b.len() as f64);
+        let n = n1 + n2;
+
+        /* Rank both samples together */
+        let combined: Vec<_> = a.iter()
+            .chain(b)
+            .copied()
+            .collect();
+        let (ranks, ties) = ranks(&combined);
+        let rank_sum: f64 = ranks[.. a.len()].iter().sum();
+
+        /* U statistic, and it's tie corrected spread */
+        let u = rank_sum - n1 * (n1 + 1.0) / 2.0;
+        let mean = n1 * n2 / 2.0;
+        let variance = n1 * n2 / 12.0 * ((n + 1.0) - ties / (n * (n - 1.0)));
+
+        /* Normal approximation, all values tied give no evidence */
+        let z = if variance > 0.0 {
+            let difference = u - mean;
+            (difference.abs() - 0.5).max(0.0).copysign(difference) / variance.sqrt()
+        } else {
+            0.0
+            };
+
+        Self {
+            u,
+            z,
+            p_value: two_sided_p_value(z),
+            effect_size: 2.0 * u / (n1 * n2) - 1.0
+            }
+        }
+
+    /** `u` getter. */
+    #[inline]
+    pub const fn u(&self) -> f64
+        { self.u }
+    /** `z` getter. */
+    #[inline]
+    pub const fn z(&self) -> f64
+        { self.z }
+    /** `p_value` getter. */
+    #[inline]
+    pub const fn p_value(&self) -> f64
+        { self.p_value }
+    /** `effect_size` getter. */
+    #[inline]
+    pub const fn effect_size(&self) -> f64
+        { self.effect_size }
+    }
+
+/** **Technical part** - structure for holding the comparison of a single metric. */
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricComparison {
+    /** Name of the metric. */
+    metric: &'static str,
+    /** Number of runs with the metric, in both sets. */
+    sizes: (usize, usize),
+    /** Medians of the metric, in both sets. */
+    medians: (f64, f64),
+    /** Possible test's results - missing if any set lacks the metric. */
+    test: Option<MannWhitney>
+    }
+
+impl MetricComparison {
+    /** `metric` getter. */
+    #[inline]
+    pub const fn metric(&self) -> &'static str
+        { self.metric }
+    /** `sizes` getter. */
+    #[inline]
+    pub const fn sizes(&self) -> (usize, usize)
+        { self.sizes }
+    /** `medians` getter. */
+    #[inline]
+    pub const fn medians(&self) -> (f64, f64)
+        { self.medians }
+    /** `test` getter. */
+    #[inline]
+    pub const fn test(&self) -> Option<MannWhitney>
+        { self.test }
+    }
+
+/** `Comparison` structure, for comparing two sets of runs metric by metric, with the Mann-Whitney U test. */
+#[derive(Debug, Clone, Serialize)]
+pub struct Comparison {
+    /** Comparisons of each metric. */
+    metrics: Box<[MetricComparison]>
+    }
+
+impl Comparison {
+    /** Metrics compared, with their extraction from the run's statistics - runs without any route rank as the worst ones, with infinite routes. */
+    const METRICS: [(&'static str, Metric); 6] = [
+        ("average route length", |stats| Some(stats.average_route_len())),
+        ("routes per ant", |stats| Some(stats.completed_routes())),
+        ("best route length", |stats| Some(stats.best_route().map_or(f64::INFINITY, BestRoute::length))),
+        ("fewest hops", |stats| Some(stats.fewest_hops_route().map_or(f64::INFINITY, |route| route.hops() as f64))),
+        ("final satiated ants", |stats| stats.ants_per_phase().last().map(|&ants| ants as f64)),
+        ("cycles run", |stats| Some(stats.cycles_run() as f64))
+        ];
+
+    /** Constructor. */
+    pub fn new(first: &[Stats], second: &[Stats]) -> Self {
+        let metrics = Self::METRICS.iter()
+            .map(|&(metric, extract)| {
+                let mut a: Vec<_> = first.iter().filter_map(extract).collect();
+                let mut b: Vec<_> = second.iter().filter_map(extract).collect();
+                a.sort_unstable_by(f64::total_cmp);
+                b.sort_unstable_by(f64::total_cmp);
+
+                let median = |sorted: &[f64]| match sorted {
+                    [] => f64::NAN,
+                    _ => quantile(sorted, 0.5)
+                    };
+                let test = (! a.is_empty() && ! b.is_empty())
+                    .then(|| MannWhitney::new(&a, &b));
+
+                MetricComparison {
+                    metric,
+                    sizes: (a.len(), b.len()),
+                    medians: (median(&a), median(&b)),
+                    test
+                    }
+                })
+            .collect();
+
+        Self { metrics }
+        }
+
+    /** `metrics` getter. */
+    #[inline]
+    pub fn metrics(&self) -> &[MetricComparison]
+        { &self.metrics }
+
+    /** Show the comparison's table. */
+    pub fn show(&self) {
+        println!("o> ------------------------------------------------ COMPARISON ------------------------------------------------ <o");
+        println!("| {:>20} | {:>5} | {:>5} | {:>10} | {:>10} | {:>10} | {:>8} | {:>10} | {:>11}", "metric", "n a", "n b", "median a", "median b", "U", "z", "p-value", "effect size");
+        for MetricComparison { metric, sizes, medians, test } in &self.metrics {
+            print!("| {:>20} | {:>5} | {:>5} | {:>10.4} | {:>10.4} |", metric, sizes.0, sizes.1, medians.0, medians.1);
+            match test {
+                Some(MannWhitney { u, z, p_value, effect_size }) =>
+                    println!(" {:>10.1} | {:>8.4} | {:>10} | {:>11.4}", u, z, show_p_value(*p_value), effect_size),
+                None =>
+                    println!(" {:>10} | {:>8} | {:>10} | {:>11}", "none", "none", "none", "none")
+                }
+            }
+        println!("o> ---------------------------------------------------------------------------------------------------------- <o");
+        }
+    }
+
+/** **Technical part** - helper function, for showing the p-value, the small ones in the scientific notation. */
+fn show_p_value(p_value: f64) -> String {
+    match p_value {
+        ..1e-4 => format!("{p_value:.2e}"),
+        _ => format!("{p_value:.4}")
+        }
+    }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* Compares the floats up to the tolerance */
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!((actual - expected).abs() <= tolerance, "{actual} isn't close to {expected}");
+        }
+
+    #[test]
+    fn mann_whitney_separated_samples() {
+        let test = MannWhitney::new(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]);
+
+        assert_close(test.u(), 0.0, 1e-12);
+        assert_close(test.z(), -1.7457431218879391, 1e-9);
+        assert_close(test.p_value(), 0.0808555983700523, 1e-6);
+        assert_close(test.effect_size(), -1.0, 1e-12);
+        }
+
+    #[test]
+    fn mann_whitney_textbook_example() {
+        /* The smaller U of the two samples is 3 */
+        let (a, b) = ([19.0, 22.0, 16.0, 29.0, 24.0], [20.0, 11.0, 17.0, 12.0]);
+        let test = MannWhitney::new(&a, &b);
+
+        assert_close(test.u(), 17.0, 1e-12);
+        assert_close(MannWhitney::new(&b, &a).u(), 3.0, 1e-12);
+        assert_close(test.z(), 1.5921683328090657, 1e-9);
+        assert_close(test.p_value(), 0.11134688653314048, 1e-6);
+        assert_close(test.effect_size(), 0.7, 1e-12);
+        }
+
+    #[test]
+    fn mann_whitney_infinite_values_rank_last() {
+        let test = MannWhitney::new(&[1.0, f64::INFINITY, f64::INFINITY], &[2.0, 3.0, f64::INFINITY]);
+        let finite = MannWhitney::new(&[1.0, 9.0, 9.0], &[2.0, 3.0, 9.0]);
+
+        assert_close(test.u(), finite.u(), 1e-12);
+        assert_close(test.p_value(), finite.p_value(), 1e-12);
+        }
+
+    #[test]
+    fn mann_whitney_with_ties() {
+        let test = MannWhitney::new(&[1.0, 2.0, 2.0, 3.0], &[2.0, 3.0, 3.0, 4.0]);
+
+        assert_close(test.u(), 3.0, 1e-12);
+        assert_close(test.z(), -1.365698202000489, 1e-9);
+        assert_close(test.p_value(), 0.17203370892182296, 1e-6);
+        }
+
+    #[test]
+    fn mann_whitney_all_tied() {
+        let test = MannWhitney::new(&[5.0, 5.0], &[5.0, 5.0, 5.0]);
+
+        assert_close(test.z(), 0.0, 0.0);
+        assert_close(test.p_value(), 1.0, 1e-6);
+        assert_close(test.effect_size(), 0.0, 1e-12);
+        }
+    }
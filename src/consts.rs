@@ -29,6 +29,8 @@ pub mod default {
     pub const PHERO_STRENGTH: f64 = 1.0;
     /** Default return behaviour. */
     pub const RETURN_BEHAVIOUR: bool = false;
+    /** Default problem solved by the ants. */
+    pub const PROBLEM: Problem = Problem::Forage;
     /** Default food consuming rate. */
     pub const CONSUME_RATE: u32 = 0;
     /** Default point selection method. */
@@ -37,12 +39,54 @@ pub mod default {
     pub const PREFERENCE_METHOD: Preference = Preference::PD;
     /** Default distance calculation metric. */
     pub const METRIC: Metric = Metric::Euclidean;
+    /** Default exponent of the point preference's factors. */
+    pub const EXPONENT: f64 = 1.0;
+    /** Default pheromone model. */
+    pub const PHEROMONE_MODEL: PheromoneModel = PheromoneModel::Point;
+    /** Default pheromone deposit method. */
+    pub const DEPOSIT_METHOD: Deposit = Deposit::Constant;
+    /** Default variant of the algorithm. */
+    pub const ALGORITHM: Algorithm = Algorithm::Generic;
+    /** Default probability of the greedy choice. */
+    pub const GREEDY_PROBABILITY: f64 = 0.9;
+    /** Default local pheromone update coefficient. */
+    pub const LOCAL_UPDATE_COEFFICIENT: f64 = 0.1;
+    /** Default initial amount of pheromones for the ACS. */
+    pub const INITIAL_PHEROMONE: f64 = 0.1;
+    /** Default lower limit of pheromones on a trail for the MMAS. */
+    pub const TRAIL_MIN: f64 = 0.05;
+    /** Default upper limit of pheromones on a trail for the MMAS. */
+    pub const TRAIL_MAX: f64 = 5.0;
+    /** Default route reinforced by the MMAS. */
+    pub const REINFORCEMENT: Reinforcement = Reinforcement::Iteration;
+    /** Default number of cycles without improvement, after which the MMAS resets trails. */
+    pub const STAGNATION_CYCLES: usize = 10;
+    /** Default weight of the best-so-far route's additional cover for the elitist variant. */
+    pub const ELITE_WEIGHT: f64 = 2.0;
+    /** Default number of top ants covering their routes for the rank-based variant. */
+    pub const RANK_COUNT: usize = 6;
     /** Default logging behaviour. */
     pub const QUIET: bool = false;
+    /** Number of the most frequent routes shown in the statistics. */
+    pub const SHOWN_ROUTES: usize = 5;
+    /** Default stopping behaviour, once all ants are satiated. */
+    pub const STOP_SATIATED: bool = false;
+    /** Default per-cycle series recording behaviour. */
+    pub const SERIES: bool = false;
+    /** Default number of cycles between the checkpoints. */
+    pub const CHECKPOINT_CYCLES: usize = 0;
+    /** Default number of finished runs between the checkpoints. */
+    pub const CHECKPOINT_RUNS: usize = 1;
     /** Default computation duration logging behaviour. */
     pub const TIMING: bool = false;
     /** Default number of simulation repetitions. */
     pub const BATCH_SIZE: u64 = 1;
+    /** Default objective of the parameter tuning. */
+    pub const OBJECTIVE: Objective = Objective::BestLength;
+    /** Default number of runs per candidate in the first round of the parameter tuning. */
+    pub const TUNING_RUNS: u64 = 4;
+    /** Default number of threads running the repetitions. */
+    pub const JOBS: u64 = 1;
     }
 
 /** Values for different kinds of calculations. */
@@ -55,6 +99,17 @@ pub mod bias {
     pub const GREAT: f64 = f64::MAX;
     /** Minimal bias, changed from `1e-6_f64` */
     pub const MINUTE: f64 = f64::MIN_POSITIVE;
+    /** Standard normal quantile for the 95% confidence interval */
+    pub const CONFIDENCE_95: f64 = 1.959963984540054;
+    /** Student's t quantiles for the 95% confidence interval, for 1 to 30 degrees of freedom */
+    pub const STUDENT_95: [f64; 30] = [
+        12.706204736174704, 4.302652729749464, 3.182446305284263, 2.776445105197799, 2.570581835636314,
+        2.446911851144969, 2.364624251592784, 2.306004135204166, 2.262157162798205, 2.228138851986274,
+        2.200985160082949, 2.178812829667229, 2.160368656462793, 2.144786687917804, 2.131449545559774,
+        2.119905299221254, 2.109815577833317, 2.100922040241036, 2.093024054408307, 2.085963447265864,
+        2.079613844727662, 2.073873067904015, 2.068657610419041, 2.063898561628021, 2.059538552753294,
+        2.055529438642871, 2.051830516480283, 2.048407141795244, 2.045229642132703, 2.042272456301238
+        ];
     }
 
 /** Limitations for arguments. */
@@ -78,17 +133,31 @@ pub mod limits {
     /** Allowed range for printing of ants' data. */
     pub const PRINTABLE_ANTS_RANGE: RangeTo<usize> = .. 0xfff;
     /** Allowed range for number of cycles. */
-    pub const CYCLES_RANGE: Range<u64> = 1 .. 100;
+    pub const CYCLES_RANGE: Range<u64> = 1 .. 100_000;
     /** Allowed range for pheromone strength. */
     pub const PHERO_RANGE: RangeFrom<f64> = 0.0 ..;
     /** Allowed range for simulation repetitions. */
     pub const BATCH_RANGE: Range<u64> = 1 .. 1000;
+    /** Allowed range for number of threads running the repetitions. */
+    pub const JOBS_RANGE: RangeInclusive<u64> = 1 ..= 1024;
     /** Allowed range for linear dispersion coefficient. */
     pub const DISPERSION_LINEAR_RANGE: RangeFrom<f64> = 0.0 ..;
     /** Allowed range for exponential dispersion coefficient. */
     pub const DISPERSION_EXPONENTIAL_RANGE: RangeFrom<f64> = 1.0 ..;
     /** Allowed range for relative dispersion coefficient. */
     pub const DISPERSION_RELATIVE_RANGE: RangeInclusive<f64> = 0.0 ..= 1.0;
+    /** Allowed range for the point preference's exponents. */
+    pub const EXPONENT_RANGE: RangeInclusive<f64> = 0.0 ..= 16.0;
+    /** Allowed range for the elite route's weight. */
+    pub const ELITE_WEIGHT_RANGE: RangeFrom<f64> = 0.0 ..;
+    /** Allowed range for number of top ants covering their routes. */
+    pub const RANK_COUNT_RANGE: RangeFrom<usize> = 1 ..;
+    /** Allowed range for the stopping relative entropy of pheromones. */
+    pub const ENTROPY_RANGE: RangeInclusive<f64> = 0.0 ..= 1.0;
+    /** Allowed range for number of cycles without improvement, before stopping. */
+    pub const PATIENCE_RANGE: RangeFrom<usize> = 1 ..;
+    /** Allowed range for probabilities, and update coefficients. */
+    pub const PROBABILITY_RANGE: RangeInclusive<f64> = 0.0 ..= 1.0;
 
     /** Shorthand for maximal number of points. */
     pub const MAX_POINTS: usize = POINTS_RANGE.end;
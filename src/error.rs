@@ -3,6 +3,10 @@
 use {
     serde_json::Error as SerdeError,
     thiserror::Error,
+    toml::{
+        de::Error as TomlDeError,
+        ser::Error as TomlSerError
+        },
     std::io::Error as IoError
     };
 
@@ -57,17 +61,53 @@ pub enum RuntimeError {
     /** Error caused by failing input validity assertion. */
     #[error("Assertion failed: {0}")]
     Assert(#[from] AssertionError),
-    /** Error caused by a shortage of food sources occured during simulation. */
+    /** Error caused by a failure occured during simulation. */
     #[error(transparent)]
-    NoFoodSource(#[from] NoFoodSourceError),
+    Simulation(#[from] SimulationError),
     /** Error caused by trying to save statistics to file. */
     #[error("An error occured while trying to save: {0}")]
-    File(#[from] SaveError)
+    File(#[from] SaveError),
+    /** Error caused by trying to load statistics from file. */
+    #[error("An error occured while trying to load: {0}")]
+    Load(#[from] LoadError),
+    /** Error caused by trying to load, or save a scenario file. */
+    #[error("An error occured while handling the scenario: {0}")]
+    Scenario(#[from] ScenarioError),
+    /** Error caused by trying to resume from a checkpoint file. */
+    #[error("An error occured while resuming from the checkpoint: {0}")]
+    Checkpoint(#[from] CheckpointError),
+    /** Error caused by a failure occured during a parameter sweep. */
+    #[error("An error occured during the sweep: {0}")]
+    Sweep(#[from] SweepError),
+    /** Error caused by a failure occured during a replay. */
+    #[error("An error occured during the replay: {0}")]
+    Replay(#[from] ReplayError)
     } 
 
 /** **Technical part** - type to represent a possible assertion failure causes. */
 #[derive(Debug, Error)]
 pub enum AssertionError {
+    /** Error caused by passing number of cycles which is out of acceptable range. */
+    #[error("The context has number of cycles out of range")]
+    InvalidCycles,
+    /** Error caused by passing number of ants which is out of acceptable range. */
+    #[error("The context has number of ants out of range")]
+    InvalidAnts,
+    /** Error caused by passing batch size which is out of acceptable range. */
+    #[error("The context has batch size out of range")]
+    InvalidBatchSize,
+    /** Error caused by passing number of threads which is out of acceptable range. */
+    #[error("The context has number of threads out of range")]
+    InvalidJobs,
+    /** Error caused by choosing a run outside of the batch. */
+    #[error("The context has run index outside of the batch")]
+    InvalidRunIndex,
+    /** Error caused by passing number of points which is out of acceptable range. */
+    #[error("The context has number of points out of range")]
+    InvalidNumberOfPoints,
+    /** Error caused by points with coordinates outside of the grid. */
+    #[error("The context has points outside of the grid")]
+    PointOutsideOfGrid,
     /** Error caused by points with identical IDs. */
     #[error("The context has non-unique point IDs")]
     NonUniquePointIds,
@@ -80,12 +120,54 @@ pub enum AssertionError {
     /** Error caused by passing pheromone strength which is out of acceptable range. */
     #[error("The context has pheromone strength out of range")]
     PheromoneOutsideOfRange,
+    /** Error caused by passing preference's exponents which are out of acceptable range. */
+    #[error("The context has preference's exponents out of range")]
+    ExponentOutsideOfRange,
+    /** Error caused by passing only one of the dispersion mode, and it's coefficient. */
+    #[error("The context has dispersion mode, and coefficient not passed together")]
+    UnpairedDispersion,
     /** Error caused by passing dispersion coefficient which is out of acceptable range. */
     #[error("The context has dispersion coefficient out of range")]
     InvalidDispersionCoefficient,
+    /** Error caused by passing greedy choice probability which is out of acceptable range. */
+    #[error("The context has greedy choice probability out of range")]
+    GreedinessOutsideOfRange,
+    /** Error caused by passing local update coefficient which is out of acceptable range. */
+    #[error("The context has local update coefficient out of range")]
+    LocalUpdateOutsideOfRange,
+    /** Error caused by passing initial pheromone amount which is out of acceptable range. */
+    #[error("The context has initial pheromone amount out of range")]
+    InitialPheromoneOutsideOfRange,
+    /** Error caused by passing MMAS trail limits which are out of acceptable range, or not increasing. */
+    #[error("The context has trail limits out of range")]
+    InvalidTrailLimits,
+    /** Error caused by passing elite weight which is out of acceptable range. */
+    #[error("The context has elite weight out of range")]
+    EliteWeightOutsideOfRange,
+    /** Error caused by passing number of top ants which is out of acceptable range. */
+    #[error("The context has number of top ants out of range")]
+    InvalidRankCount,
+    /** Error caused by using the ACS without the relative dispersion. */
+    #[error("The context uses ACS without the relative dispersion")]
+    AcsWithoutRelativeDispersion,
+    /** Error caused by using the ACS without the pseudo-random proportional selection. */
+    #[error("The context uses ACS without the proportional selection")]
+    AcsWithoutProportionalSelection,
     /** Error caused by actions containing invalid IDs. */
     #[error("The context has action with non-existant IDs")]
     NonOverlappingActionIds,
+    /** Error caused by passing number of cycles without improvement, which is out of acceptable range. */
+    #[error("The context has patience out of range")]
+    InvalidPatience,
+    /** Error caused by passing relative entropy threshold, which is out of acceptable range. */
+    #[error("The context has entropy threshold out of range")]
+    EntropyOutsideOfRange,
+    /** Error caused by passing a target, which isn't a point of the grid, or is the anthill. */
+    #[error("The context has target point outside of the grid, or at the anthill")]
+    InvalidTarget,
+    /** Error caused by passing a target for the tour. */
+    #[error("The context sets target point for the tour")]
+    TargetInTour,
     /** Error caused by anthill reciving any amount of food during simulation - it should remain empty. */
     #[error("The context has or sets food amount to the anthill")]
     NonEmptyAnthill
@@ -96,6 +178,31 @@ pub enum AssertionError {
 #[error("Available food sources ran out")]
 pub struct NoFoodSourceError;
 
+/** **Technical part** - type to represent a possible simulation's failures. */
+#[derive(Debug, Error)]
+pub enum SimulationError {
+    /** Error caused by a shortage of food sources occured during simulation. */
+    #[error(transparent)]
+    NoFoodSource(#[from] NoFoodSourceError),
+    /** Error caused by trying to save a checkpoint file. */
+    #[error("An error occured while saving the checkpoint: {0}")]
+    Checkpoint(#[from] CheckpointError)
+    }
+
+/** **Technical part** - type to represent a possible checkpoints' handling errors. */
+#[derive(Debug, Error)]
+pub enum CheckpointError {
+    /** Error caused by problems with file handling. */
+    #[error(transparent)]
+    IO(#[from] IoError),
+    /** Error caused by problems with parsing/writing JSON values. */
+    #[error(transparent)]
+    Serde(#[from] SerdeError),
+    /** Error caused by the checkpoint's settings failing the assertion. */
+    #[error("Assertion failed: {0}")]
+    Assert(#[from] AssertionError)
+    }
+
 /** **Technical part** - type to represent a possible statistics' saving errors. */
 #[derive(Debug, Error)]
 pub enum SaveError {
@@ -105,4 +212,87 @@ pub enum SaveError {
     /** Error caused by problems with parsing/writing JSON values. */
     #[error(transparent)]
     Serde(#[from] SerdeError)
-    }
\ No newline at end of file
+    }
+
+/** **Technical part** - type to represent a possible statistics' loading errors. */
+#[derive(Debug, Error)]
+pub enum LoadError {
+    /** Error caused by problems with file handling. */
+    #[error(transparent)]
+    IO(#[from] IoError),
+    /** Error caused by problems with parsing JSON values. */
+    #[error(transparent)]
+    Serde(#[from] SerdeError)
+    }
+
+/** **Technical part** - type to represent a possible scenario files' handling errors. */
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    /** Error caused by file's extension being neither `toml`, nor `json`. */
+    #[error("Unsupported scenario format, expected a `.toml`, or `.json` file")]
+    UnsupportedFormat,
+    /** Error caused by problems with file handling. */
+    #[error(transparent)]
+    IO(#[from] IoError),
+    /** Error caused by problems with parsing/writing JSON values. */
+    #[error(transparent)]
+    Json(#[from] SerdeError),
+    /** Error caused by problems with parsing TOML values. */
+    #[error(transparent)]
+    TomlDe(#[from] TomlDeError),
+    /** Error caused by problems with writing TOML values. */
+    #[error(transparent)]
+    TomlSer(#[from] TomlSerError)
+    }
+
+/** **Technical part** - type to represent a possible parameter sweeps' errors. */
+#[derive(Debug, Error)]
+pub enum SweepError {
+    /** Error caused by a parameter naming no configuration's field. */
+    #[error("Unknown parameter `{0}`")]
+    UnknownParameter(String),
+    /** Error caused by a parameter without values. */
+    #[error("Missing values of the parameter `{0}`")]
+    MissingValues(String),
+    /** Error caused by malformed values of a parameter. */
+    #[error("Invalid values of the parameter `{0}`, expected `start..end:step`, or a comma separated list")]
+    InvalidValues(String),
+    /** Error caused by failing input validity assertion. */
+    #[error("Assertion failed: {0}")]
+    Assert(#[from] AssertionError),
+    /** Error caused by a failure occured during simulation. */
+    #[error(transparent)]
+    Simulation(#[from] SimulationError),
+    /** Error caused by problems with file handling. */
+    #[error(transparent)]
+    IO(#[from] IoError),
+    /** Error caused by problems with parsing/writing JSON values, also the parameters' values not fitting their fields. */
+    #[error(transparent)]
+    Serde(#[from] SerdeError)
+    }
+
+/** **Technical part** - type to represent a possible replays' errors. */
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    /** Error caused by an entry past the file's end. */
+    #[error("Missing entry {0}, the file holds {1} entries")]
+    MissingEntry(usize, usize),
+    /** Error caused by an entry saved without the manifest, by an older version. */
+    #[error("Entry {0} has no manifest, it was saved by an older version")]
+    MissingManifest(usize),
+    /** Error caused by the regenerated statistics differing from the stored ones. */
+    #[error("Entry {0} was not reproduced, differing fields: {1}")]
+    Mismatch(usize, String),
+    /** Error caused by failing input validity assertion. */
+    #[error("Assertion failed: {0}")]
+    Assert(#[from] AssertionError),
+    /** Error caused by a failure occured during simulation. */
+    #[error(transparent)]
+    Simulation(#[from] SimulationError),
+    /** Error caused by trying to load statistics from file. */
+    #[error(transparent)]
+    Load(#[from] LoadError),
+    /** Error caused by problems with writing JSON values. */
+    #[error(transparent)]
+    Serde(#[from] SerdeError)
+    }
@@ -0,0 +1,148 @@
+/*!
+# Generic Ant Algorithm
+
+A university project - an implementation of Ant Colony Optimisation Alogrithm, packaged with a small CLI tool for inspecting results, allows setting some parameters on the run, with the main route backed into the executable
+
+## Library usage
+The simulation can be embedded directly, without going through the CLI tool:
+```no_run
+use ant_algorithm::{
+    Config,
+    DisjointConfig,
+    Simulator
+    };
+
+/* Describe the simulation */
+let config = Config::builder()
+    .cycles(20)
+    .ants(50)
+    .build();
+let disjoint = DisjointConfig {
+    batch_size: 10,
+    ..DisjointConfig::default()
+    };
+
+/* Run it, and inspect the results */
+let mut simulator = Simulator::new(config, disjoint)?;
+simulator.simulate()?;
+
+for stats in simulator.stats() {
+    println!("{}", stats.average_route_len());
+    }
+# Ok::<(), ant_algorithm::error::RuntimeError>(())
+```
+
+The simulation can also be advanced cycle by cycle with `Simulator::step`, and watched by custom `Observer`s:
+```no_run
+use ant_algorithm::{
+    AntHill,
+    Config,
+    DisjointConfig,
+    Observer,
+    Route,
+    Simulator,
+    World
+    };
+
+/* Count the ants' moves, and print the best route after each cycle */
+#[derive(Default)]
+struct Watcher {
+    moves: usize
+    }
+
+impl Observer for Watcher {
+    fn on_ant_move(&mut self, _ant: usize, _route: &Route) {
+        self.moves += 1;
+        }
+
+    fn on_cycle_end(&mut self, phase: usize, ant_hill: &AntHill, _world: &World) {
+        println!("{phase}: {} moves, best: {:?}", self.moves, ant_hill.best_route());
+        }
+    }
+
+let mut simulator = Simulator::new(Config::default(), DisjointConfig::default())?;
+simulator.add_observer(Watcher::default());
+while simulator.step()? {}
+# Ok::<(), ant_algorithm::error::RuntimeError>(())
+```
+
+## Acknowledgements
+Based on `Klasyczny algorytm mrówkowy v.2.0` by Feliks Kurp
+*/
+
+#![warn(missing_docs)]
+
+/* Logic modules */
+mod anthill;
+mod world;
+mod utils;
+
+/* Technical modules */
+mod compare;
+pub mod consts;
+pub mod error;
+#[doc(hidden)]
+pub mod log;
+mod observer;
+mod replay;
+mod scenario;
+mod simul;
+mod sweep;
+mod tech;
+mod tune;
+
+
+pub use crate::{
+    anthill::AntHill,
+    compare::{
+        Comparison,
+        MannWhitney,
+        MetricComparison
+        },
+    observer::{
+        Observer,
+        PhaseLogger
+        },
+    replay::Replay,
+    scenario::Scenario,
+    simul::Simulator,
+    sweep::{
+        Parameter,
+        Sweep,
+        SweepRow
+        },
+    tech::{
+        Action,
+        Algorithm,
+        AveragedStats,
+        Checkpointing,
+        BestRoute,
+        Config,
+        ConfigBuilder,
+        Deposit,
+        Dispersion,
+        DisjointConfig,
+        Exponents,
+        Id,
+        Manifest,
+        Metric,
+        Objective,
+        PhaseSnapshot,
+        PheromoneModel,
+        Preference,
+        Problem,
+        Record,
+        Reinforcement,
+        Route,
+        Selection,
+        Spread,
+        Stats,
+        StopReason
+        },
+    tune::{
+        Tuner,
+        TuningRound
+        },
+    utils::Point,
+    world::World
+    };
@@ -1,30 +1,19 @@
 /*!
 # Generic Ant Algorithm
 
-A university project - an implementation of Ant Colony Optimisation Alogrithm, packaged with a small CLI tool for inspecting results, allows setting some parameters on the run, with the main route backed into the executable
+The CLI tool - a thin client of the `ant_algorithm` library, for inspecting results, and setting some parameters on the run
 
 ## Acknowledgements
 Based on `Klasyczny algorytm mrówkowy v.2.0` by Feliks Kurp
 */
 
-/* Logic modules */
-mod anthill;
-mod world;
-mod utils;
-
 /* Technical modules */
 mod args;
-mod consts;
-mod error;
-mod log;
-mod simul;
-mod tech;
 
 
 use {
-    clap::Parser,
-    fastrand::seed,
     std::{
+        path::Path,
         time::{
             Instant,
             Duration
@@ -32,11 +21,25 @@ use {
         process::ExitCode
         },
     sqds_tools::ResolveExitCode,
-    crate::{
-        args::Args,
+    ant_algorithm::{
+        error,
         error::RuntimeError,
-        simul::Simulator,
-        tech::ActionsConfig
+        info,
+        Comparison,
+        Config,
+        DisjointConfig,
+        Objective,
+        Replay,
+        Scenario,
+        Simulator,
+        Sweep,
+        Tuner
+        },
+    crate::args::{
+        command_line,
+        ActionsConfig,
+        Args,
+        Command
         }
     };
 
@@ -50,20 +53,30 @@ use std::panic::{
 
 /** Fallible logic of the application. */
 fn run() -> Result<(), RuntimeError> {
-    /* Parse the CL arguments */
-    let args = Args::parse();
-
-    /* Break args into config structs */
-    let (actions, config, disjoint) = args.into();
-    let ActionsConfig { counts_time, output_path } = actions;
-
-    /* If set, seed the random generator */
-    if let Some(&value) = config.seed.as_ref() {
-        seed(value);
+    /* Parse the CL arguments, and break them into config structs */
+    let (actions, config, disjoint) = Args::load()?;
+    let ActionsConfig { counts_time, output_path, summary_path, resume_path, command } = actions;
+
+    /* Run the subcommand instead, if passed */
+    match command {
+        Some(Command::Sweep { table, parameters }) =>
+            return sweep(config, disjoint, &parameters, table.as_deref(), counts_time),
+        Some(Command::Tune { objective, by_cycle, runs, write_scenario, parameters }) =>
+            return tune(config, disjoint, &parameters, (objective, by_cycle, runs as usize), write_scenario.as_deref()),
+        Some(Command::Compare { first, second }) =>
+            return compare(&first, &second),
+        Some(Command::Convert { input, output }) =>
+            return Simulator::convert_file(&input, &output).map(drop),
+        Some(Command::Replay { file, entry }) =>
+            return replay(&file, entry, ! disjoint.no_logging),
+        None => ()
         }
 
-    /* Create a new simulation manager */
-    let mut simulation = Simulator::new(config, disjoint)?;
+    /* Create a new simulation manager, or resume the saved one */
+    let mut simulation = match resume_path.as_deref() {
+        Some(path) => Simulator::resume(path, disjoint.checkpointing, disjoint.jobs)?,
+        None => Simulator::new(config, disjoint)?
+        };
 
     /* Get current time */
     let start = Instant::now();
@@ -87,10 +100,100 @@ fn run() -> Result<(), RuntimeError> {
         /* Try to save statistics */
         simulation.write_to_file(path)?;
         }
+
+    /* Save averaged statistics to a file */
+    if let Some(path) = summary_path.as_deref() {
+        simulation.write_summary_to_file(path)?;
+        }
     
     Ok(())
     }
 
+/** Runs the parameter sweep, over the base configuration. */
+fn sweep(config: Config, disjoint: DisjointConfig, parameters: &[String], table_path: Option<&Path>, counts_time: bool) -> Result<(), RuntimeError> {
+    /* Create a new sweep manager */
+    let parameters = Command::parse_parameters(parameters)?;
+    let mut sweep = Sweep::new(config, disjoint, parameters)?;
+
+    /* Run all combinations, and measure the time */
+    let start = Instant::now();
+    sweep.simulate()?;
+    let stop = start.elapsed();
+
+    /* Show informations */
+    sweep.show();
+    if counts_time {
+        show_time(stop);
+        }
+
+    /* Save the result table to a file */
+    if let Some(path) = table_path {
+        sweep.write_to_file(path)?;
+        }
+
+    Ok(())
+    }
+
+/** Runs the parameter tuning, over the base configuration, and shows the winner as a command line. */
+fn tune(config: Config, disjoint: DisjointConfig, parameters: &[String], settings: (Objective, Option<usize>, usize), scenario_path: Option<&Path>) -> Result<(), RuntimeError> {
+    let (objective, by_cycle, runs) = settings;
+
+    /* Create a new tuning manager */
+    let parameters = Command::parse_parameters(parameters)?;
+    let mut tuner = Tuner::new(config, disjoint.clone(), parameters, (objective, by_cycle), runs)?;
+
+    /* Run the rounds */
+    tuner.simulate()?;
+
+    /* Show informations */
+    tuner.show();
+    let Some(winner) = tuner.winning_config() else {
+        return Ok(());
+        };
+    println!("{}", command_line(&winner, &disjoint));
+
+    /* Save the winning configuration to a scenario file */
+    if let Some(path) = scenario_path {
+        Scenario::from_configs(&winner, &disjoint)
+            .write_to_file(path)?;
+        info!("Scenario saved in '{}'", path.display());
+        }
+
+    Ok(())
+    }
+
+/** Compares two statistics' files, and shows the table. */
+fn compare(first: &Path, second: &Path) -> Result<(), RuntimeError> {
+    let first = Simulator::read_from_file(first)?;
+    let second = Simulator::read_from_file(second)?;
+
+    Comparison::new(&first, &second)
+        .show();
+
+    Ok(())
+    }
+
+/** Replays the saved runs, and checks whether they're reproduced. */
+fn replay(path: &Path, entry: Option<usize>, logs: bool) -> Result<(), RuntimeError> {
+    let replay = Replay::from_file(path, logs)?;
+    let entries = match entry {
+        Some(entry) => entry ..= entry,
+        None => 0 ..= replay.records().len().saturating_sub(1)
+        };
+
+    for entry in entries {
+        let simulation = replay.replay(entry)?;
+        if logs {
+            simulation.show_stats();
+            }
+
+        /* Write information */
+        info!("Entry {} reproduced bit for bit", entry);
+        }
+
+    Ok(())
+    }
+
 /** **Technical part** - prints simulation's duration data. */
 fn show_time(duration: Duration) {
     println!(
@@ -110,7 +213,7 @@ pub fn panic_hook(panic_info: &PanicHookInfo<'_>) {
     let msg = panic_info.payload_as_str()
         .unwrap_or("Unknown error");
 
-    critical!("{msg}");
+    ant_algorithm::critical!("{msg}");
     }
 
 /** Entry point of the program. */
@@ -0,0 +1,115 @@
+/*! **Technical module** - hooks for watching the simulation's progress. */
+
+use {
+    core::fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult
+        },
+    crate::{
+        anthill::AntHill,
+        tech::{
+            Route,
+            StopReason
+            },
+        world::World
+        }
+    };
+
+
+
+/** `Observer` trait, for watching the simulation - every callback does nothing by default, so only the needed ones have to be implemented. */
+pub trait Observer {
+    /** Called before the first cycle of each run, with the run's number. */
+    fn on_run_start(&mut self, _run: usize, _ant_hill: &AntHill, _world: &World) {}
+
+    /** Called after an ant moved, with the ant's number, and it's route ending at the new position. */
+    fn on_ant_move(&mut self, _ant: usize, _route: &Route) {}
+
+    /** Called after an ant reached it's goal, with the ant's number, and the finished route with it's length. */
+    fn on_food_reached(&mut self, _ant: usize, _route: &Route, _length: f64) {}
+
+    /** Called after each cycle, with the cycle's number, counted from 0. */
+    fn on_cycle_end(&mut self, _phase: usize, _ant_hill: &AntHill, _world: &World) {}
+
+    /** Called after the last cycle of each run, with the run's number, and the reason it stopped. */
+    fn on_run_end(&mut self, _run: usize, _ant_hill: &AntHill, _world: &World, _stop_reason: StopReason) {}
+    }
+
+/** **Technical part** - collection of observers, notified in the order of adding. */
+#[derive(Default)]
+pub struct Observers(Vec<Box<dyn Observer>>);
+
+impl Observers {
+    /** Add an observer at the end. */
+    pub fn push(&mut self, observer: Box<dyn Observer>) {
+        self.0.push(observer);
+        }
+
+    /** Check whether there are no observers. */
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+        }
+    }
+
+impl Debug for Observers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Observers({})", self.0.len())
+        }
+    }
+
+impl Observer for Observers {
+    fn on_run_start(&mut self, run: usize, ant_hill: &AntHill, world: &World) {
+        for observer in &mut self.0 {
+            observer.on_run_start(run, ant_hill, world);
+            }
+        }
+
+    fn on_ant_move(&mut self, ant: usize, route: &Route) {
+        for observer in &mut self.0 {
+            observer.on_ant_move(ant, route);
+            }
+        }
+
+    fn on_food_reached(&mut self, ant: usize, route: &Route, length: f64) {
+        for observer in &mut self.0 {
+            observer.on_food_reached(ant, route, length);
+            }
+        }
+
+    fn on_cycle_end(&mut self, phase: usize, ant_hill: &AntHill, world: &World) {
+        for observer in &mut self.0 {
+            observer.on_cycle_end(phase, ant_hill, world);
+            }
+        }
+
+    fn on_run_end(&mut self, run: usize, ant_hill: &AntHill, world: &World, stop_reason: StopReason) {
+        for observer in &mut self.0 {
+            observer.on_run_end(run, ant_hill, world, stop_reason);
+            }
+        }
+    }
+
+/** `PhaseLogger` structure, an observer printing the colony, and the world at the beginning of each run, and after each cycle. */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhaseLogger;
+
+impl PhaseLogger {
+    /** Show the banner, with the colony, and the world. */
+    fn show(banner: &str, ant_hill: &AntHill, world: &World) {
+        println!("{banner}");
+        ant_hill.show();
+        world.show();
+        println!("o>=======================<o\n");
+        }
+    }
+
+impl Observer for PhaseLogger {
+    fn on_run_start(&mut self, _run: usize, ant_hill: &AntHill, world: &World) {
+        Self::show("o>====== BEGINNING ======<o", ant_hill, world);
+        }
+
+    fn on_cycle_end(&mut self, phase: usize, ant_hill: &AntHill, world: &World) {
+        Self::show(&format!("o>======  PHASE {:>2} ======<o", phase + 1), ant_hill, world);
+        }
+    }
@@ -0,0 +1,100 @@
+/*! **Technical module** - reproducibility audit of the saved runs. */
+
+use {
+    serde_json::{
+        to_value,
+        Value
+        },
+    std::path::Path,
+    crate::{
+        info,
+        error::ReplayError,
+        simul::Simulator,
+        tech::{
+            DisjointConfig,
+            Record,
+            Stats
+            }
+        }
+    };
+
+
+
+/** `Replay` structure, for re-running the saved runs from their manifests, and checking whether the statistics are reproduced bit for bit. */
+#[derive(Debug, Clone)]
+pub struct Replay {
+    /** Whether the replayed runs should be logged. */
+    logs: bool,
+    /** Saved runs. */
+    records: Vec<Record>
+    }
+
+impl Replay {
+    /** Constructor. */
+    pub const fn new(records: Vec<Record>, logs: bool) -> Self
+        { Self { logs, records } }
+
+    /** Constructor, reads the saved runs from the statistics' file. */
+    pub fn from_file(path: &Path, logs: bool) -> Result<Self, ReplayError> {
+        let records = Simulator::read_records_from_file(path)?;
+
+        Ok(Self::new(records, logs))
+        }
+
+    /** `records` getter. */
+    #[inline]
+    pub fn records(&self) -> &[Record]
+        { &self.records }
+
+    /** Re-run the saved entry, counted from 0, with the chosen run of the batch, and it's seed - returns the simulator, if the statistics match the stored ones. */
+    pub fn replay(&self, entry: usize) -> Result<Simulator, ReplayError> {
+        let record = self.records.get(entry)
+            .ok_or(ReplayError::MissingEntry(entry, self.records.len()))?;
+        let manifest = record.manifest()
+            .ok_or(ReplayError::MissingManifest(entry))?;
+        let stored = record.stats();
+
+        /* Write information */
+        info!("Replaying entry {} - run {} of the batch with seed {}, saved by version {}", entry, stored.run(), manifest.seed(), manifest.version());
+
+        /* Rebuild the simulation, running only the stored run */
+        let mut config = manifest.config().clone();
+        config.seed = Some(manifest.seed());
+        let disjoint_config = DisjointConfig {
+            no_logging: ! self.logs,
+            records_series: manifest.records_series(),
+            batch_size: manifest.batch_size().max(stored.run() + 1),
+            jobs: 1,
+            run_index: Some(stored.run()),
+            grid: manifest.grid().to_vec(),
+            actions: manifest.actions().to_vec(),
+            checkpointing: None
+            };
+        let mut simulator = Simulator::new(config, disjoint_config)?;
+        simulator.simulate()?;
+
+        /* Compare the serialized statistics, floats are written in their shortest exact form */
+        let [regenerated] = simulator.stats() else {
+            unreachable!("The replay should always simulate a single run");
+            };
+        let fields = differing_fields(stored, regenerated)?;
+        if ! fields.is_empty() {
+            return Err(ReplayError::Mismatch(entry, fields.join(", ")));
+            }
+
+        Ok(simulator)
+        }
+    }
+
+/** **Technical part** - helper function, for listing the statistics' fields, which differ - compared as written, so even the zeros' signs count. */
+fn differing_fields(stored: &Stats, regenerated: &Stats) -> Result<Vec<String>, ReplayError> {
+    let (Value::Object(stored), Value::Object(regenerated)) = (to_value(stored)?, to_value(regenerated)?) else {
+        unreachable!("The statistics should always serialize to an object");
+        };
+
+    Ok(stored.keys()
+        .chain(regenerated.keys().filter(|name| ! stored.contains_key(*name)))
+        .filter(|name| stored.get(*name).map(Value::to_string) != regenerated.get(*name).map(Value::to_string))
+        .cloned()
+        .collect())
+    }
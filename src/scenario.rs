@@ -0,0 +1,233 @@
+/*! **Technical module** - scenario files loading, and saving. */
+
+use {
+    serde::{
+        Deserialize,
+        Serialize
+        },
+    serde_json::Value,
+    std::{
+        fs::{
+            read_to_string,
+            write
+            },
+        path::Path
+        },
+    crate::{
+        error::ScenarioError,
+        tech::*,
+        utils::Point
+        }
+    };
+
+
+
+/** **Technical part** - structure for holding a simulation's definition read from a file, every field is optional. */
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Scenario {
+    /** Possible number of cycles. */
+    pub cycles: Option<usize>,
+    /** Possible number of ants. */
+    pub ants: Option<usize>,
+    /** Possible pheromone strengths. */
+    pub pheromone: Option<f64>,
+    /** Possible number of decision points. */
+    pub decision: Option<usize>,
+    /** Possible consumption rate. */
+    pub rate: Option<u32>,
+    /** Possible return behaviour. */
+    pub returns: Option<bool>,
+    /** Possible problem solved by the ants. */
+    pub problem: Option<Problem>,
+    /** Possible target point. */
+    pub target: Option<Id>,
+    /** Possible point selection method. */
+    pub select: Option<Selection>,
+    /** Possible point prefrence calculation method. */
+    pub preference: Option<Preference>,
+    /** Possible distance calculation metric. */
+    pub metric: Option<Metric>,
+    /** Possible pheromone factor's exponent. */
+    pub alpha: Option<f64>,
+    /** Possible distance factor's exponent. */
+    pub beta: Option<f64>,
+    /** Possible food factor's exponent. */
+    pub gamma: Option<f64>,
+    /** Possible dispersion behaviour. */
+    pub dispersion: Option<Dispersion>,
+    /** Possible dispersion coefficient. */
+    pub factor: Option<f64>,
+    /** Possible pheromone model. */
+    pub pheromone_model: Option<PheromoneModel>,
+    /** Possible pheromone deposit method. */
+    pub deposit: Option<Deposit>,
+    /** Possible variant of the algorithm. */
+    pub algorithm: Option<Algorithm>,
+    /** Possible probability of the greedy choice. */
+    pub q0: Option<f64>,
+    /** Possible local pheromone update coefficient. */
+    pub xi: Option<f64>,
+    /** Possible initial amount of pheromones. */
+    pub tau0: Option<f64>,
+    /** Possible lower limit of pheromones on a trail. */
+    pub tau_min: Option<f64>,
+    /** Possible upper limit of pheromones on a trail. */
+    pub tau_max: Option<f64>,
+    /** Possible route reinforced at the end of each cycle. */
+    pub reinforcement: Option<Reinforcement>,
+    /** Possible number of cycles without improvement, after which trails are reset. */
+    pub stagnation: Option<usize>,
+    /** Possible weight of the best-so-far route's additional cover. */
+    pub elite_weight: Option<f64>,
+    /** Possible number of top ants covering their routes. */
+    pub rank_count: Option<usize>,
+    /** Possible stopping behaviour, once all ants are satiated. */
+    pub stop_satiated: Option<bool>,
+    /** Possible number of cycles without improvement, after which the run stops. */
+    pub patience: Option<usize>,
+    /** Possible relative entropy of pheromones, below which the run stops. */
+    pub entropy: Option<f64>,
+    /** Possible random number generator seed. */
+    pub seed: Option<u64>,
+    /** Possible world grid, the first point is the anthill. */
+    pub grid: Option<Vec<Point>>,
+    /** Possible food changes made during the runtime. */
+    pub actions: Option<Vec<Action>>
+    }
+
+impl Scenario {
+    /** Read the scenario from a TOML, or JSON file - the format is chosen by the extension. */
+    pub fn from_file(path: &Path) -> Result<Self, ScenarioError> {
+        let extension = path.extension()
+            .and_then(|ext| ext.to_str());
+
+        match extension {
+            Some("toml") =>
+                Ok(toml::from_str(&read_to_string(path)?)?),
+            Some("json") =>
+                Ok(serde_json::from_str(&read_to_string(path)?)?),
+            _ =>
+                Err(ScenarioError::UnsupportedFormat)
+            }
+        }
+
+    /** Write the scenario to a TOML, or JSON file - the format is chosen by the extension. */
+    pub fn write_to_file(&self, path: &Path) -> Result<(), ScenarioError> {
+        let extension = path.extension()
+            .and_then(|ext| ext.to_str());
+
+        let contents = match extension {
+            Some("toml") =>
+                toml::to_string_pretty(self)?,
+            Some("json") =>
+                serde_json::to_string_pretty(self)?,
+            _ =>
+                return Err(ScenarioError::UnsupportedFormat)
+            };
+
+        write(path, contents)?;
+
+        Ok(())
+        }
+
+    /** Drop the fields, which the predicate holds for - they're matched by their names, which are the same as the arguments' ids. */
+    pub fn without(self, mut dropped: impl FnMut(&str) -> bool) -> Result<Self, ScenarioError> {
+        let Value::Object(mut fields) = serde_json::to_value(self)? else {
+            unreachable!("The scenario should always serialize to an object");
+            };
+        fields.retain(|name, _| ! dropped(name));
+
+        Ok(serde_json::from_value(Value::Object(fields))?)
+        }
+
+    /** Constructor, with every field set from the configs. */
+    pub fn from_configs(config: &Config, disjoint_config: &DisjointConfig) -> Self {
+        Self {
+            cycles: Some(config.cycles),
+            ants: Some(config.ants),
+            pheromone: Some(config.pheromone),
+            decision: Some(config.decision),
+            rate: Some(config.rate),
+            returns: Some(config.returns),
+            problem: Some(config.problem),
+            target: config.target,
+            select: Some(config.select),
+            preference: Some(config.preference),
+            metric: Some(config.metric),
+            alpha: Some(config.alpha),
+            beta: Some(config.beta),
+            gamma: Some(config.gamma),
+            dispersion: config.dispersion,
+            factor: Some(config.factor).filter(|factor| ! factor.is_nan()),
+            pheromone_model: Some(config.pheromone_model),
+            deposit: Some(config.deposit),
+            algorithm: Some(config.algorithm),
+            q0: Some(config.q0),
+            xi: Some(config.xi),
+            tau0: Some(config.tau0),
+            tau_min: Some(config.tau_min),
+            tau_max: Some(config.tau_max),
+            reinforcement: Some(config.reinforcement),
+            stagnation: Some(config.stagnation),
+            elite_weight: Some(config.elite_weight),
+            rank_count: Some(config.rank_count),
+            stop_satiated: Some(config.stop_satiated),
+            patience: config.patience,
+            entropy: config.entropy,
+            seed: config.seed,
+            grid: Some(disjoint_config.grid.clone()),
+            actions: Some(disjoint_config.actions.clone())
+            }
+        }
+
+    /** Fill in the configs' fields, which are set in the scenario. */
+    pub fn apply(self, config: &mut Config, disjoint_config: &mut DisjointConfig) {
+        let Self {
+            cycles, ants, pheromone, decision, rate, returns, problem, target,
+            select, preference, metric, alpha, beta, gamma,
+            dispersion, factor, pheromone_model, deposit,
+            algorithm, q0, xi, tau0, tau_min, tau_max, reinforcement, stagnation, elite_weight, rank_count,
+            stop_satiated, patience, entropy, seed,
+            grid, actions
+            } = self;
+
+        /* Main config */
+        if let Some(value) = cycles { config.cycles = value; }
+        if let Some(value) = ants { config.ants = value; }
+        if let Some(value) = pheromone { config.pheromone = value; }
+        if let Some(value) = decision { config.decision = value; }
+        if let Some(value) = rate { config.rate = value; }
+        if let Some(value) = returns { config.returns = value; }
+        if let Some(value) = problem { config.problem = value; }
+        if target.is_some() { config.target = target; }
+        if let Some(value) = select { config.select = value; }
+        if let Some(value) = preference { config.preference = value; }
+        if let Some(value) = metric { config.metric = value; }
+        if let Some(value) = alpha { config.alpha = value; }
+        if let Some(value) = beta { config.beta = value; }
+        if let Some(value) = gamma { config.gamma = value; }
+        if dispersion.is_some() { config.dispersion = dispersion; }
+        if let Some(value) = factor { config.factor = value; }
+        if let Some(value) = pheromone_model { config.pheromone_model = value; }
+        if let Some(value) = deposit { config.deposit = value; }
+        if let Some(value) = algorithm { config.algorithm = value; }
+        if let Some(value) = q0 { config.q0 = value; }
+        if let Some(value) = xi { config.xi = value; }
+        if let Some(value) = tau0 { config.tau0 = value; }
+        if let Some(value) = tau_min { config.tau_min = value; }
+        if let Some(value) = tau_max { config.tau_max = value; }
+        if let Some(value) = reinforcement { config.reinforcement = value; }
+        if let Some(value) = stagnation { config.stagnation = value; }
+        if let Some(value) = elite_weight { config.elite_weight = value; }
+        if let Some(value) = rank_count { config.rank_count = value; }
+        if let Some(value) = stop_satiated { config.stop_satiated = value; }
+        if patience.is_some() { config.patience = patience; }
+        if entropy.is_some() { config.entropy = entropy; }
+        if seed.is_some() { config.seed = seed; }
+
+        /* Disjoint config */
+        if let Some(value) = grid { disjoint_config.grid = value; }
+        if let Some(value) = actions { disjoint_config.actions = value; }
+        }
+    }
@@ -2,39 +2,81 @@
 
 use {
     rustc_hash::FxBuildHasher,
+    serde::{
+        Deserialize,
+        Serialize
+        },
     serde_json::{
         from_reader,
+        from_str,
+        to_vec,
+        to_writer,
         to_writer_pretty
         },
     std::{
+        borrow::Cow,
         collections::{
             HashMap,
             HashSet,
             hash_map::Values
             },
-        fs::File,
-        path::Path
+        fs::{
+            File,
+            OpenOptions,
+            remove_file,
+            rename
+            },
+        io::{
+            BufRead,
+            BufReader,
+            BufWriter,
+            Write
+            },
+        mem::replace,
+        ops::Range,
+        path::Path,
+        sync::atomic::{
+            AtomicUsize,
+            Ordering
+            },
+        thread::scope
         },
     crate::{
         info,
-        anthill::AntHill,
+        warning,
+        anthill::{
+            AntHill,
+            AntHillState
+            },
         consts::limits::*,
         error::*,
+        observer::{
+            Observer,
+            Observers,
+            PhaseLogger
+            },
         tech::*,
         utils::Point,
-        world::World
+        world::{
+            World,
+            WorldState
+            }
         }
     };
 
 
 
 /** `Simulation` structure, for managing the instatiating, asserting correct configuration, simulation running, prinitng, and saving data. */
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Simulator {
     /** Whether logging should happen. */
     logs: bool,
     /** Number or repetitions. */
     batch_size: usize,
+    /** Number of threads running the repetitions. */
+    jobs: usize,
+    /** Number of the first run, for simulators running a part of the batch. */
+    first_run: usize,
     /** Simulation's configuration. */
     config: Config,
     /** Amount of food to add on corresponding cycle, and point. */
@@ -43,19 +85,135 @@ pub struct Simulator {
     ant_hill: AntHill,
     /** World space object. */
     world: World,
-    /** Statistics for each simulation run. */
-    stats: Vec<Stats>
+    /** Number of the next cycle of the current run. */
+    phase: usize,
+    /** Observer collecting the statistics. */
+    collector: StatsCollector,
+    /** Other observers, notified after the collector. */
+    observers: Observers,
+    /** Possible checkpoints' settings. */
+    checkpointing: Option<Checkpointing>,
+    /** Number of cycles since the last checkpoint. */
+    cycles_since_checkpoint: usize
+    }
+
+/** **Technical part** - observer collecting the statistics of each run. */
+#[derive(Debug, Clone)]
+struct StatsCollector {
+    /** Number of cycles in a run. */
+    cycles: usize,
+    /** Whether per-cycle series are recorded. */
+    records_series: bool,
+    /** Simulation's seed, from which the runs' seeds are derived. */
+    seed: u64,
+    /** Statistics for each finished run. */
+    stats: Vec<Stats>,
+    /** Statistics gathered during the current run. */
+    progress: RunProgress
+    }
+
+impl StatsCollector {
+    /** Constructor. */
+    fn new(cycles: usize, records_series: bool, batch_size: usize, seed: u64) -> Self {
+        Self {
+            cycles,
+            records_series,
+            seed,
+            stats: Vec::with_capacity(batch_size),
+            progress: RunProgress::new(cycles, records_series)
+            }
+        }
+    }
+
+impl Observer for StatsCollector {
+    fn on_cycle_end(&mut self, _phase: usize, ant_hill: &AntHill, world: &World) {
+        self.progress.ants_per_phase.push(ant_hill.satiated_ants_count());
+        self.progress.lengths_per_phase.push(ant_hill.cycle_route_lengths());
+        if let Some(snapshots) = &mut self.progress.series {
+            snapshots.push(PhaseSnapshot::new(ant_hill, world));
+            }
+        }
+
+    fn on_run_end(&mut self, run: usize, ant_hill: &AntHill, world: &World, stop_reason: StopReason) {
+        /* Take the run's statistics, and start the next one */
+        let fresh = RunProgress::new(self.cycles, self.records_series);
+        let RunProgress { ants_per_phase, lengths_per_phase, series } = replace(&mut self.progress, fresh);
+
+        /* Add final statistics */
+        let run_values = (run, Simulator::run_seed(self.seed, run));
+        self.stats.push(Stats::new(ant_hill, world, run_values, ants_per_phase, &lengths_per_phase, series, stop_reason));
+        }
+    }
+
+/** **Technical part** - structure for holding statistics gathered during the current run. */
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+struct RunProgress {
+    /** Number of satiated ants for each cycle. */
+    ants_per_phase: Vec<usize>,
+    /** Shortest, and average finished route length for each cycle. */
+    lengths_per_phase: Vec<(Option<f64>, Option<f64>)>,
+    /** Possible snapshots of each cycle. */
+    series: Option<Vec<PhaseSnapshot>>
+    }
+
+impl RunProgress {
+    /** Constructor. */
+    fn new(cycles: usize, records_series: bool) -> Self {
+        Self {
+            ants_per_phase: Vec::with_capacity(cycles),
+            lengths_per_phase: Vec::with_capacity(cycles),
+            series: records_series.then(|| Vec::with_capacity(cycles))
+            }
+        }
+    }
+
+/** **Technical part** - structure for holding the simulation's full state, saved in the checkpoint files. */
+#[derive(Debug, Deserialize, Serialize)]
+struct Checkpoint<'a> {
+    /** Simulation's configuration. */
+    config: Config,
+    /** Whether logging should happen. */
+    logs: bool,
+    /** Whether per-cycle series are recorded. */
+    records_series: bool,
+    /** Number or repetitions. */
+    batch_size: usize,
+    /** Number of the first run, for simulators running a part of the batch. */
+    #[serde(default)]
+    first_run: usize,
+    /** World grid, with points' initial food. */
+    grid: Vec<Point>,
+    /** Food changes made during the runtime. */
+    actions: Vec<Action>,
+    /** Statistics for each finished run. */
+    stats: Cow<'a, [Stats]>,
+    /** Number of the next cycle of the current run. */
+    phase: usize,
+    /** Number of cycles since the last checkpoint. */
+    #[serde(default)]
+    cycles_since_checkpoint: usize,
+    /** Statistics gathered during the current run. */
+    progress: Cow<'a, RunProgress>,
+    /** The colony's changing part. */
+    ant_hill: AntHillState,
+    /** The world's changing part. */
+    world: WorldState,
+    /** State of the random generator. */
+    rng_state: u64
     }
 
 impl Simulator {
     /** Constructor. */
-    pub fn new(config: Config, disjoint_config: DisjointConfig) -> Result<Self, AssertionError> {
+    pub fn new(mut config: Config, disjoint_config: DisjointConfig) -> Result<Self, AssertionError> {
         /* Unpack config */
-        let Config { cycles, ref ants, .. } = config;
-        let DisjointConfig { no_logging, batch_size, grid, actions } = disjoint_config;
+        let Config { cycles, ants, .. } = config;
+        let DisjointConfig { no_logging, records_series, batch_size, jobs, run_index, grid, actions, checkpointing } = disjoint_config;
 
         /* Preproces arguments */
         let num_of_points = grid.len();
+        if ! POINTS_RANGE.contains(&num_of_points) {
+            return Err(AssertionError::InvalidNumberOfPoints);
+            }
         let anthill = grid.first()
             .expect("The grid should always have first point");
 
@@ -64,13 +222,22 @@ impl Simulator {
 
         /* Assert some conditions to avoid unnecessary errors */
         let grid_values = (grid.as_slice(), anthill, num_of_points);
-        Self::assert(&config, grid_values, actions.values())?;
+        Self::assert(&config, (batch_size, jobs, run_index), grid_values, actions.values())?;
+
+        /* If not set, draw the seed, as every run's random stream is derived from it */
+        let seed = *config.seed.get_or_insert_with(|| fastrand::u64(..));
+
+        /* If set, run only the chosen run of the batch */
+        let (first_run, batch_size) = match run_index {
+            Some(run) => (run, 1),
+            None => (0, batch_size)
+            };
 
         /* Check whether the simulation runs once */
         let singleton = batch_size == 1;
         
-        /* Set whether to log information */
-        let logs = if no_logging || (PRINTABLE_ANTS_RANGE.contains(ants) && singleton) {
+        /* Set whether to log information, the chosen run is always logged */
+        let logs = if no_logging || run_index.is_some() || (PRINTABLE_ANTS_RANGE.contains(&ants) && singleton) {
             ! no_logging
         } else {
             info!("Logging hidden");
@@ -83,22 +250,40 @@ impl Simulator {
         /* Create World object */
         let world = World::new(grid, &config);
 
+        /* Print phases through an observer, if applicable */
+        let mut observers = Observers::default();
+        if logs {
+            observers.push(Box::new(PhaseLogger));
+            }
+
         /* Create Simulator object */
         Ok(Self {
             logs,
             batch_size,
+            jobs,
+            first_run,
             config,
             actions,
-            stats: Vec::with_capacity(batch_size),         
             ant_hill,
-            world
+            world,
+            phase: 0,
+            collector: StatsCollector::new(cycles, records_series, batch_size, seed),
+            observers,
+            checkpointing,
+            cycles_since_checkpoint: 0
             })
         }
 
     /** Static, helper function for asserting simulation's conditions. */
-    fn assert(config: &Config, grid_values: (&[Point], &Point, usize), actions: Values<'_, usize, Box<[(Id, u32)]>>) -> Result<(), AssertionError> {
+    fn assert(config: &Config, batch_values: (usize, usize, Option<usize>), grid_values: (&[Point], &Point, usize), actions: Values<'_, usize, Box<[(Id, u32)]>>) -> Result<(), AssertionError> {
         /* Unpack config */
-        let Config { pheromone, decision, dispersion, factor, .. } = config;
+        let Config {
+            cycles, ants, pheromone, decision, problem, target,
+            alpha, beta, gamma, dispersion, factor,
+            select, algorithm, q0, xi, tau0, tau_min, tau_max, elite_weight, rank_count,
+            patience, entropy, ..
+            } = config;
+        let (batch_size, jobs, run_index) = batch_values;
         let (grid, anthill, num_of_points) = grid_values;
 
         /* Prepare variables */
@@ -110,6 +295,18 @@ impl Simulator {
             .collect();
 
         /* Assert! */
+        if ! CYCLES_RANGE.contains(&(*cycles as u64))
+            { return Err(AssertionError::InvalidCycles); }
+        if ! ANTS_RANGE.contains(&(*ants as u64))
+            { return Err(AssertionError::InvalidAnts); }
+        if ! BATCH_RANGE.contains(&(batch_size as u64))
+            { return Err(AssertionError::InvalidBatchSize); }
+        if ! JOBS_RANGE.contains(&(jobs as u64))
+            { return Err(AssertionError::InvalidJobs); }
+        if run_index.is_some_and(|run| run >= batch_size)
+            { return Err(AssertionError::InvalidRunIndex); }
+        if ! grid.iter().all(|Point { x, y, .. }| GRID_RANGE.contains(x) && GRID_RANGE.contains(y))
+            { return Err(AssertionError::PointOutsideOfGrid); }
         if point_ids.len() != num_of_points
             { return Err(AssertionError::NonUniquePointIds); }
         if point_pos.len() != num_of_points
@@ -118,8 +315,36 @@ impl Simulator {
             { return Err(AssertionError::InvalidDecisionPoints); }
         if ! PHERO_RANGE.contains(pheromone)
             { return Err(AssertionError::PheromoneOutsideOfRange); }
+        if ! [alpha, beta, gamma].iter().all(|exponent| EXPONENT_RANGE.contains(*exponent))
+            { return Err(AssertionError::ExponentOutsideOfRange); }
+        if dispersion.is_some() == factor.is_nan()
+            { return Err(AssertionError::UnpairedDispersion); }
         if dispersion.is_some_and(|mode| ! mode.is_factor_valid(factor))
             { return Err(AssertionError::InvalidDispersionCoefficient); }
+        if ! PROBABILITY_RANGE.contains(q0)
+            { return Err(AssertionError::GreedinessOutsideOfRange); }
+        if ! PROBABILITY_RANGE.contains(xi)
+            { return Err(AssertionError::LocalUpdateOutsideOfRange); }
+        if ! PHERO_RANGE.contains(tau0)
+            { return Err(AssertionError::InitialPheromoneOutsideOfRange); }
+        if *algorithm == Algorithm::Mmas && (! PHERO_RANGE.contains(tau_min) || ! PHERO_RANGE.contains(tau_max) || tau_min >= tau_max)
+            { return Err(AssertionError::InvalidTrailLimits); }
+        if ! ELITE_WEIGHT_RANGE.contains(elite_weight)
+            { return Err(AssertionError::EliteWeightOutsideOfRange); }
+        if ! RANK_COUNT_RANGE.contains(rank_count)
+            { return Err(AssertionError::InvalidRankCount); }
+        if patience.is_some_and(|cycles| ! PATIENCE_RANGE.contains(&cycles))
+            { return Err(AssertionError::InvalidPatience); }
+        if entropy.is_some_and(|threshold| ! ENTROPY_RANGE.contains(&threshold))
+            { return Err(AssertionError::EntropyOutsideOfRange); }
+        if *algorithm == Algorithm::Acs && ! matches!(dispersion, Some(Dispersion::Relative))
+            { return Err(AssertionError::AcsWithoutRelativeDispersion); }
+        if *algorithm == Algorithm::Acs && ! matches!(select, Selection::Proportional)
+            { return Err(AssertionError::AcsWithoutProportionalSelection); }
+        if target.is_some_and(|id| ! point_ids.contains(&id) || id == anthill.id)
+            { return Err(AssertionError::InvalidTarget); }
+        if target.is_some() && *problem == Problem::Tour
+            { return Err(AssertionError::TargetInTour); }
         if ! point_ids.is_superset(&actions_ids)
             { return Err(AssertionError::NonOverlappingActionIds); }
         if ! anthill.is_empty() && actions_ids.contains(&anthill.id) 
@@ -146,83 +371,361 @@ impl Simulator {
             .collect()
         }
 
-    /** Run the simulation. */
-    pub fn simulate(&mut self) -> Result<(), NoFoodSourceError> {
-        /* Simulate number of times */
-        for _ in 0 .. self.batch_size {
-            /* Container for statistics */
-            let mut ants_per_phase = Vec::with_capacity(self.config.cycles);
+    /** Run the simulation, or it's remaining part - the runs are spread over the threads, if applicable. */
+    pub fn simulate(&mut self) -> Result<(), SimulationError> {
+        /* Finish the current run first, as it could have been resumed, or stepped through */
+        while self.phase != 0 && self.step()? {}
+
+        /* Run the remaining runs in parallel, in waves between the checkpoints */
+        if self.runs_in_parallel() {
+            while ! self.is_finished() {
+                let done = self.collector.stats.len();
+                let remaining = self.batch_size - done;
+                let wave = match &self.checkpointing {
+                    Some(Checkpointing { runs, .. }) if *runs != 0 => self.jobs.min(remaining),
+                    _ => remaining
+                    };
+
+                /* Add statistics in the runs' order */
+                let stats = self.simulate_runs(done .. done + wave)?;
+                self.collector.stats.extend(stats);
+
+                /* Save the checkpoint, if it's due */
+                if let Some(Checkpointing { path, runs, .. }) = &self.checkpointing
+                    && *runs != 0 && done / runs != self.collector.stats.len() / runs {
+                    self.write_checkpoint(path)?;
+                    }
+                }
+            }
 
-            /* Print information, if applicable */
-            self.show_phase(None);
+        /* Run the rest one by one */
+        while self.step()? {}
 
-            /* Begin the simulation */
-            for phase in 0 .. self.config.cycles {
-                /* Make simulation step */
-                self.ant_hill.action(&mut self.world)?;
+        /* The finished batch needs no checkpoint */
+        if let Some(Checkpointing { path, .. }) = &self.checkpointing
+            && path.exists() {
+            remove_file(path)
+                .map_err(CheckpointError::from)?;
+            }
 
-                /* Disperse pheromones */
-                self.world.disperse_pheromons();
+        Ok(())
+        }
 
-                /* Execute actions, if applicable */
-                if let Some(acts) = self.actions.get(&phase) {
-                    for &(id, amount) in acts {
-                        self.world.set_food_source(id, amount);
+    /** Check whether the runs can be spread over the threads - observers, and checkpoints between the cycles need them in order. */
+    fn runs_in_parallel(&self) -> bool {
+        self.jobs > 1
+            && self.observers.is_empty()
+            && self.checkpointing.as_ref().is_none_or(|checkpointing| checkpointing.cycles == 0)
+        }
+
+    /** Simulate the range of runs on multiple threads, each run on it's own copy of the colony, and the world. */
+    fn simulate_runs(&self, runs: Range<usize>) -> Result<Vec<Stats>, SimulationError> {
+        let Self { config, actions, ant_hill, world, collector, .. } = self;
+        let next_run = AtomicUsize::new(runs.start);
+        let threads = self.jobs.min(runs.len());
+
+        /* Each thread takes the next run, until none are left */
+        let finished = scope(|scope| {
+            let handles: Vec<_> = (0 .. threads)
+                .map(|_| scope.spawn(|| {
+                    let mut finished = Vec::new();
+                    loop {
+                        let run = next_run.fetch_add(1, Ordering::Relaxed);
+                        if run >= runs.end {
+                            break;
+                            }
+
+                        let mut worker = Self::worker(config, actions, ant_hill, world, collector, run);
+                        worker.simulate()?;
+                        finished.extend(worker.collector.stats.into_iter().map(|stats| (run, stats)));
                         }
-                    }
+                    Ok(finished)
+                    }))
+                .collect();
+
+            handles.into_iter()
+                .map(|handle| handle.join().expect("The simulation thread shouldn't panic"))
+                .collect::<Result<Vec<_>, SimulationError>>()
+            })?;
+
+        /* Sort by the runs' order */
+        let mut finished: Vec<_> = finished.into_iter()
+            .flatten()
+            .collect();
+        finished.sort_by_key(|&(run, _)| run);
+
+        Ok(finished.into_iter().map(|(_, stats)| stats).collect())
+        }
+
+    /** Constructor, for a simulator running only a single run of the batch. */
+    fn worker(config: &Config, actions: &HashMap<usize, Box<[(Id, u32)]>, FxBuildHasher>, ant_hill: &AntHill, world: &World, collector: &StatsCollector, run: usize) -> Self {
+        Self {
+            logs: false,
+            batch_size: 1,
+            jobs: 1,
+            first_run: run,
+            config: config.clone(),
+            actions: actions.clone(),
+            ant_hill: ant_hill.clone(),
+            world: world.clone(),
+            phase: 0,
+            collector: StatsCollector::new(config.cycles, collector.records_series, 1, collector.seed),
+            observers: Observers::default(),
+            checkpointing: None,
+            cycles_since_checkpoint: 0
+            }
+        }
+
+    /** Skip the first runs of the batch, to simulate only the rest with their own random streams - has to be called before simulating. */
+    pub fn skip_runs(&mut self, runs: usize) {
+        let runs = runs.min(self.batch_size);
+        self.first_run += runs;
+        self.batch_size -= runs;
+        }
 
-                /* Gather statistics */
-                ants_per_phase.push(self.ant_hill.satiated_ants_count());
+    /** Advance the simulation by one cycle, and return whether any cycles remain. */
+    pub fn step(&mut self) -> Result<bool, SimulationError> {
+        if self.is_finished() {
+            return Ok(false);
+            }
+        let run = self.first_run + self.collector.stats.len();
+        let phase = self.phase;
+
+        /* Start the run's own random stream, and notify about it's beginning, if applicable */
+        if phase == 0 {
+            fastrand::seed(Self::run_seed(self.collector.seed, run));
+            self.collector.on_run_start(run, &self.ant_hill, &self.world);
+            self.observers.on_run_start(run, &self.ant_hill, &self.world);
+            }
 
-                /* Print information, if applicable */
-                self.show_phase(Some(phase));
+        /* Make simulation step */
+        self.ant_hill.action(&mut self.world, &mut self.observers)?;
+
+        /* Disperse pheromones */
+        self.world.disperse_pheromons();
+
+        /* Execute actions, if applicable */
+        if let Some(acts) = self.actions.get(&phase) {
+            for &(id, amount) in acts {
+                self.world.set_food_source(id, amount);
                 }
+            }
 
-            /* Gather final statistics */
-            let stats = Stats::new(&self.ant_hill, &self.world, ants_per_phase);
+        /* Notify about the cycle's end */
+        self.collector.on_cycle_end(phase, &self.ant_hill, &self.world);
+        self.observers.on_cycle_end(phase, &self.ant_hill, &self.world);
+        self.phase += 1;
+        self.cycles_since_checkpoint += 1;
+
+        /* Runs use the whole budget, unless any of the stopping rules applies */
+        let stop_reason = self.check_stop_rules()
+            .or_else(|| (self.phase == self.config.cycles).then_some(StopReason::Budget));
+
+        if let Some(reason) = stop_reason {
+            self.finish_run(run, reason)?;
+        } else if let Some(Checkpointing { path, cycles, .. }) = &self.checkpointing
+            && *cycles != 0 && self.cycles_since_checkpoint >= *cycles {
+            /* Save the checkpoint, if it's due */
+            self.write_checkpoint(path)?;
+            self.cycles_since_checkpoint = 0;
+            }
 
-            /* Add statistics */
-            self.stats.push(stats);
+        Ok(! self.is_finished())
+        }
 
-            /* Reset */
-            self.ant_hill.reset();
-            self.world.reset();
+    /** Notify about the run's end, and reset for the next one. */
+    fn finish_run(&mut self, run: usize, stop_reason: StopReason) -> Result<(), CheckpointError> {
+        /* Notify about the run's end */
+        self.collector.on_run_end(run, &self.ant_hill, &self.world, stop_reason);
+        self.observers.on_run_end(run, &self.ant_hill, &self.world, stop_reason);
+
+        /* Reset */
+        self.ant_hill.reset();
+        self.world.reset();
+        self.phase = 0;
+
+        /* Save the checkpoint, if it's due */
+        if let Some(Checkpointing { path, runs, .. }) = &self.checkpointing
+            && *runs != 0 && self.collector.stats.len().is_multiple_of(*runs) {
+            self.write_checkpoint(path)?;
             }
 
         Ok(())
         }
 
+    /** Add an observer, notified after the previously added ones. */
+    pub fn add_observer<O>(&mut self, observer: O)
+    where O: Observer + 'static {
+        self.observers.push(Box::new(observer));
+        }
+
+    /** Write the simulation's current state to a checkpoint file, between the cycles. */
+    pub fn write_checkpoint(&self, path: &Path) -> Result<(), CheckpointError> {
+        let checkpoint = Checkpoint {
+            config: self.config.clone(),
+            logs: self.logs,
+            records_series: self.collector.records_series,
+            batch_size: self.batch_size,
+            first_run: self.first_run,
+            grid: self.world.initial_grid(),
+            actions: self.actions_list(),
+            stats: Cow::Borrowed(&self.collector.stats),
+            phase: self.phase,
+            cycles_since_checkpoint: self.cycles_since_checkpoint,
+            progress: Cow::Borrowed(&self.collector.progress),
+            ant_hill: self.ant_hill.state(),
+            world: self.world.state(),
+            rng_state: fastrand::get_seed()
+            };
+
+        /* Write into a temporary file first, so a killed job can't leave a broken checkpoint */
+        let tmp_path = path.with_extension("tmp");
+        let mut file = BufWriter::new(File::create(&tmp_path)?);
+        to_writer(&mut file, &checkpoint)?;
+        file.flush()?;
+        rename(tmp_path, path)?;
+
+        Ok(())
+        }
+
+    /** Constructor, resuming the simulation from a checkpoint file. */
+    pub fn resume(path: &Path, checkpointing: Option<Checkpointing>, jobs: usize) -> Result<Self, CheckpointError> {
+        /* Read the checkpoint */
+        let file = BufReader::new(File::open(path)?);
+        let Checkpoint {
+            config, logs, records_series, batch_size, first_run, grid, actions,
+            stats, phase, cycles_since_checkpoint, progress, ant_hill, world, rng_state
+            } = from_reader(file)?;
+
+        /* Rebuild the simulation */
+        let disjoint_config = DisjointConfig {
+            no_logging: ! logs,
+            records_series,
+            batch_size,
+            jobs,
+            run_index: None,
+            grid,
+            actions,
+            checkpointing
+            };
+        let mut simulator = Self::new(config, disjoint_config)?;
+
+        /* Restore it's state */
+        simulator.first_run = first_run;
+        simulator.collector.stats = stats.into_owned();
+        simulator.phase = phase;
+        simulator.cycles_since_checkpoint = cycles_since_checkpoint;
+        simulator.collector.progress = progress.into_owned();
+        simulator.ant_hill.restore(ant_hill);
+        simulator.world.restore(world);
+        fastrand::seed(rng_state);
+
+        /* Write information */
+        info!("Resumed from '{}'", path.display());
+
+        Ok(simulator)
+        }
+
+    /** Derive the seed of the run's random stream from the simulation's seed, and the run's number, counted from 0.
+     * 
+     * The seed is a single SplitMix64 step over `seed + (run + 1) * 0x9e3779b97f4a7c15`, with wrapping arithmetic - so any run of a batch can be reproduced alone, on any thread.
+     */
+    pub const fn run_seed(seed: u64, run: usize) -> u64 {
+        let mut z = seed.wrapping_add((run as u64).wrapping_add(1).wrapping_mul(0x9e3779b97f4a7c15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+        }
+
+    /** Rebuild the list of actions from the map. */
+    fn actions_list(&self) -> Vec<Action> {
+        let mut actions: Vec<_> = self.actions.iter()
+            .flat_map(|(&cycle, acts)| acts.iter()
+                .map(move |&(id, food_amount)| Action { cycle, id, food_amount })
+                )
+            .collect();
+
+        /* Keep the order stable */
+        actions.sort_by_key(|action| action.cycle);
+        actions
+        }
+
+    /** Check the stopping rules in order, at the end of a cycle. */
+    fn check_stop_rules(&self) -> Option<StopReason> {
+        let Config { stop_satiated, patience, entropy, .. } = self.config;
+
+        if stop_satiated && self.ant_hill.has_all_ants_satiated() {
+            return Some(StopReason::Satiated);
+            }
+        if patience.is_some_and(|cycles| self.ant_hill.unimproved_cycles() >= cycles) {
+            return Some(StopReason::Patience);
+            }
+        if let Some(threshold) = entropy
+            && self.world.pheromone_entropy().is_some_and(|value| value < threshold) {
+            return Some(StopReason::Entropy);
+            }
+
+        None
+        }
+
     /** Show the simulation's statistics based on their number. */
     pub fn show_stats(&self) {
-        match self.stats.as_slice() {
+        match self.collector.stats.as_slice() {
             [single] =>
                 single.show(),
-            many => 
-                AveragedStats::new(
-                    self.config.cycles,
-                    self.world.number_of_points(),
-                    many
-                    ).show()
+            _ => 
+                self.averaged_stats().show()
             }
         }
 
-    /** Show the simulation's phase statistics, based on phase number. */
-    fn show_phase(&self, phase: Option<usize>) {
-        if ! self.logs {
-            return;
-            }
-
-        /* Show correct banner */
-        if let Some(phase) = phase.and_then(|phase_num| phase_num.checked_add(1)) {
-            println!("o>======  PHASE {:>2} ======<o", phase);
-        } else {
-            println!("o>====== BEGINNING ======<o");
-            }
-        
-        /* Show phase stats */
-        self.ant_hill.show();
-        self.world.show();
-        println!("o>=======================<o\n");
+    /** `config` getter. */
+    #[inline]
+    pub const fn config(&self) -> &Config
+        { &self.config }
+    /** `batch_size` getter. */
+    #[inline]
+    pub const fn batch_size(&self) -> usize
+        { self.batch_size }
+    /** `jobs` getter. */
+    #[inline]
+    pub const fn jobs(&self) -> usize
+        { self.jobs }
+    /** `ant_hill` getter. */
+    #[inline]
+    pub const fn ant_hill(&self) -> &AntHill
+        { &self.ant_hill }
+    /** `world` getter. */
+    #[inline]
+    pub const fn world(&self) -> &World
+        { &self.world }
+    /** `stats` getter. */
+    #[inline]
+    pub fn stats(&self) -> &[Stats]
+        { &self.collector.stats }
+    /** `phase` getter. */
+    #[inline]
+    pub const fn phase(&self) -> usize
+        { self.phase }
+    /** Check whether all runs are finished. */
+    #[inline]
+    pub fn is_finished(&self) -> bool
+        { self.collector.stats.len() >= self.batch_size }
+    /** `averaged_stats` getter. */
+    pub fn averaged_stats(&self) -> AveragedStats {
+        AveragedStats::new(
+            self.config.cycles,
+            self.world.number_of_points(),
+            &self.collector.stats
+            )
+        }
+    /** Manifest describing the simulation, for the saved statistics. */
+    pub fn manifest(&self) -> Manifest {
+        Manifest::new(
+            self.config.clone(),
+            (self.world.initial_grid(), self.actions_list()),
+            self.batch_size,
+            self.collector.records_series
+            )
         }
 
     /** Show the simulation's summary. */
@@ -237,35 +740,183 @@ impl Simulator {
         self.show_stats();
         }
 
-    /** Write statistics to file. */
+    /** Write averaged statistics, and their spread to file. */
+    pub fn write_summary_to_file(&self, path: &Path) -> Result<(), SaveError> {
+        /* File writer handle */
+        let file = File::create(path)?;
+
+        /* Try writing averaged statistics to the file */
+        to_writer_pretty(file, &self.averaged_stats())?;
+
+        /* Write information */
+        info!("Summary saved in '{}'", path.display());
+
+        Ok(())
+        }
+
+    /** Read statistics from file, written by `write_to_file`. */
+    pub fn read_from_file(path: &Path) -> Result<Vec<Stats>, LoadError> {
+        let records = Self::read_records_from_file(path)?;
+
+        Ok(records.into_iter()
+            .map(Record::into_stats)
+            .collect())
+        }
+
+    /** Read records from file, written by `write_to_file` - a JSON array, or JSON Lines for the `ndjson`, and `jsonl` extensions, where the corrupted lines are skipped - bare statistics of the older versions are read without the manifest. */
+    pub fn read_records_from_file(path: &Path) -> Result<Vec<Record>, LoadError> {
+        /* File reader handle */
+        let file = BufReader::new(File::open(path)?);
+
+        /* Try reading records from the JSON array */
+        if ! is_json_lines(path) {
+            return Ok(from_reader(file)?);
+            }
+
+        /* Read records line by line */
+        let mut data = Vec::new();
+        for (number, line) in file.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+                }
+
+            match from_str(&line) {
+                Ok(record) => data.push(record),
+                Err(err) => warning!("Skipped line {} of '{}': {}", number + 1, path.display(), err)
+                }
+            }
+
+        Ok(data)
+        }
+
+    /** Write statistics to file, each run wrapped with the simulation's manifest - JSON Lines are appended, while the JSON array is read, and rewritten whole. */
     pub fn write_to_file(&mut self, path: &Path) -> Result<(), SaveError> {
-        /* Empty statistics container */
-        let mut data = Vec::with_capacity(self.batch_size);
+        let manifest = self.manifest();
+        let records: Vec<_> = self.collector.stats.iter()
+            .map(|stats| Record::new(Some(manifest.clone()), stats.clone()))
+            .collect();
+        Self::append_to_file(path, &records)?;
+
+        /* Write information */
+        info!("Statistics saved in '{}'", path.display());
+
+        Ok(())
+        }
+
+    /** Append records to file - a JSON array, or JSON Lines for the `ndjson`, and `jsonl` extensions. */
+    pub fn append_to_file(path: &Path, records: &[Record]) -> Result<(), SaveError> {
+        if is_json_lines(path) {
+            /* File appending handle */
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?;
+
+            /* Write each run in a single call, so jobs sharing the file don't interleave */
+            for record in records {
+                let mut line = to_vec(record)?;
+                line.push(b'\n');
+                file.write_all(&line)?;
+                }
+
+            return Ok(());
+            }
+
+        /* Empty records container */
+        let mut data = Vec::with_capacity(records.len());
         
         /* If file exists, pull it's contents */
         if path.exists() {
             /* File reader handle */
-            let file = File::open(path)?;
+            let file = BufReader::new(File::open(path)?);
 
             /* Extract current contents */
-            let contents: Box<[_]> = from_reader(file)?;
+            let contents: Box<[Record]> = from_reader(file)?;
 
-            /* Push old statistics from the file */
+            /* Push old records from the file */
             data.extend(contents);
             }
-        
+
+        /* Push current records */
+        data.extend_from_slice(records);
+
+        Self::save_to_file(path, &data)
+        }
+
+    /** Save records to file, replacing it's contents - a JSON array, or JSON Lines for the `ndjson`, and `jsonl` extensions. */
+    pub fn save_to_file(path: &Path, records: &[Record]) -> Result<(), SaveError> {
         /* File writer handle */
-        let file = File::create(path)?;
+        let mut file = BufWriter::new(File::create(path)?);
 
-        /* Push current statistics */
-        data.extend_from_slice(&self.stats);
+        /* Try writing records to the file */
+        if is_json_lines(path) {
+            for record in records {
+                to_writer(&mut file, record)?;
+                file.write_all(b"\n")?;
+                }
+        } else {
+            to_writer_pretty(&mut file, records)?;
+            }
+
+        file.flush()?;
+
+        Ok(())
+        }
 
-        /* Try writing statistics to the file */
-        to_writer_pretty(file, &data)?;
+    /** Convert statistics' file between the JSON array, and JSON Lines - the formats are chosen by the extensions, returns the number of runs. */
+    pub fn convert_file(input: &Path, output: &Path) -> Result<usize, RuntimeError> {
+        let data = Self::read_records_from_file(input)?;
+        Self::save_to_file(output, &data)?;
 
         /* Write information */
-        info!("Statistics saved in '{}'", path.display());
+        info!("Converted {} runs from '{}' to '{}'", data.len(), input.display(), output.display());
 
-        Ok(())
+        Ok(data.len())
+        }
+    }
+
+/** **Technical part** - helper function, for checking whether the statistics' file holds JSON Lines, by it's extension. */
+fn is_json_lines(path: &Path) -> bool {
+    matches!(path.extension().and_then(|ext| ext.to_str()), Some("ndjson" | "jsonl"))
+    }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resumed_batch_matches_uninterrupted_one() {
+        let path = std::env::temp_dir().join(format!("ant-algorithm-checkpoint-{}.json", std::process::id()));
+        let config = Config {
+            cycles: 20,
+            seed: Some(7),
+            ..Config::default()
+            };
+        let disjoint_config = |checkpointing| DisjointConfig {
+            no_logging: true,
+            records_series: true,
+            batch_size: 3,
+            jobs: 1,
+            checkpointing,
+            ..DisjointConfig::default()
+            };
+        let checkpointing = Checkpointing { path: path.clone(), cycles: 7, runs: 1 };
+
+        let mut uninterrupted = Simulator::new(config.clone(), disjoint_config(None)).unwrap();
+        uninterrupted.simulate().unwrap();
+
+        /* Stop in the middle of the second run, the last checkpoint is a few cycles behind */
+        let mut interrupted = Simulator::new(config, disjoint_config(Some(checkpointing.clone()))).unwrap();
+        for _ in 0 .. 30 {
+            interrupted.step().unwrap();
+            }
+        drop(interrupted);
+
+        let mut resumed = Simulator::resume(&path, Some(checkpointing), 1).unwrap();
+        resumed.simulate().unwrap();
+
+        assert_eq!(to_vec(resumed.stats()).unwrap(), to_vec(uninterrupted.stats()).unwrap());
+        assert!(! path.exists(), "The finished batch's checkpoint should be removed");
         }
-    }
\ No newline at end of file
+    }
@@ -0,0 +1,365 @@
+/*! **Technical module** - parameter sweeps over the simulation's configuration. */
+
+use {
+    core::fmt::Write,
+    serde::Serialize,
+    serde_json::{
+        from_value,
+        to_value,
+        to_writer_pretty,
+        Map,
+        Number,
+        Value
+        },
+    std::{
+        collections::BTreeMap,
+        fs::File,
+        path::Path
+        },
+    crate::{
+        info,
+        error::SweepError,
+        simul::Simulator,
+        tech::{
+            AveragedStats,
+            Config,
+            DisjointConfig
+            }
+        }
+    };
+
+
+
+/** `Parameter` structure, for holding a configuration's field, and the values it takes in the sweep. */
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    /** Name of the configuration's field. */
+    name: String,
+    /** Values taken by the field. */
+    values: Box<[Value]>
+    }
+
+impl Parameter {
+    /** Constructor, takes the field's name - dashes are read as underscores - and it's values, either as an inclusive `start..end:step` range, or a comma separated list. */
+    pub fn new(name: &str, values: &str) -> Result<Self, SweepError> {
+        let name = name.replace('-', "_");
+        let invalid = || SweepError::InvalidValues(name.clone());
+
+        /* Parse the range, or the list */
+        let values: Box<[_]> = match values.split_once("..") {
+            Some((start, rest)) => {
+                let (end, step) = rest.split_once(':')
+                    .ok_or_else(invalid)?;
+                parse_range(start, end, step)
+                    .ok_or_else(invalid)?
+                },
+            None => values.split(',')
+                .map(parse_value)
+                .collect()
+            };
+
+        if values.is_empty() {
+            return Err(invalid());
+            }
+
+        Ok(Self { name, values })
+        }
+
+    /** `name` getter. */
+    #[inline]
+    pub fn name(&self) -> &str
+        { &self.name }
+    /** `values` getter. */
+    #[inline]
+    pub fn values(&self) -> &[Value]
+        { &self.values }
+    }
+
+/** **Technical part** - structure for holding a row of the sweep's result table - the parameters' values alongside the batch's averaged statistics. */
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepRow {
+    /** Values of the swept parameters. */
+    #[serde(flatten)]
+    parameters: BTreeMap<String, Value>,
+    /** Averaged statistics of the batch. */
+    #[serde(flatten)]
+    stats: AveragedStats
+    }
+
+impl SweepRow {
+    /** `parameters` getter. */
+    #[inline]
+    pub const fn parameters(&self) -> &BTreeMap<String, Value>
+        { &self.parameters }
+    /** `stats` getter. */
+    #[inline]
+    pub const fn stats(&self) -> &AveragedStats
+        { &self.stats }
+    }
+
+/** `Sweep` structure, for running a batch for every combination of the parameters' values. */
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    /** Base configuration, overridden by the parameters. */
+    config: Config,
+    /** Settings shared by all batches. */
+    disjoint_config: DisjointConfig,
+    /** Swept parameters. */
+    parameters: Box<[Parameter]>,
+    /** Result table, a row for each finished combination. */
+    rows: Vec<SweepRow>
+    }
+
+impl Sweep {
+    /** Constructor, batches run without logging, and checkpoints. */
+    pub fn new(config: Config, disjoint_config: DisjointConfig, parameters: Vec<Parameter>) -> Result<Self, SweepError> {
+        let disjoint_config = DisjointConfig {
+            no_logging: true,
+            run_index: None,
+            checkpointing: None,
+            ..disjoint_config
+            };
+
+        /* Check whether all parameters name the configuration's fields */
+        let Value::Object(fields) = to_value(&config)? else {
+            unreachable!("The configuration should always serialize to an object");
+            };
+        if let Some(parameter) = parameters.iter().find(|parameter| ! fields.contains_key(parameter.name())) {
+            return Err(SweepError::UnknownParameter(parameter.name.clone()));
+            }
+
+        Ok(Self {
+            config,
+            disjoint_config,
+            parameters: parameters.into_boxed_slice(),
+            rows: Vec::new()
+            })
+        }
+
+    /** Cartesian product of the parameters' values, with the last parameter changing the fastest. */
+    pub fn combinations(&self) -> Vec<BTreeMap<String, Value>> {
+        let mut combinations = vec![BTreeMap::new()];
+
+        for Parameter { name, values } in &self.parameters {
+            combinations = combinations.into_iter()
+                .flat_map(|combination| values.iter()
+                    .map(move |value| {
+                        let mut combination = combination.clone();
+                        combination.insert(name.clone(), value.clone());
+                        combination
+                        })
+                    )
+                .collect();
+            }
+
+        combinations
+        }
+
+    /** Run a batch for every combination, in order. */
+    pub fn simulate(&mut self) -> Result<(), SweepError> {
+        /* Configure all combinations first, so invalid values fail early */
+        let combinations = self.combinations();
+        let configs = combinations.iter()
+            .map(|parameters| configure(&self.config, parameters))
+            .collect::<Result<Vec<_>, _>>()?;
+        let count = combinations.len();
+
+        for (number, (parameters, config)) in combinations.into_iter().zip(configs).enumerate() {
+            /* Write information */
+            info!("Combination {}/{}: {}", number + 1, count, show_parameters(&parameters));
+
+            /* Run the batch */
+            let mut simulator = Simulator::new(config, self.disjoint_config.clone())?;
+            simulator.simulate()?;
+
+            /* Add the row */
+            let stats = simulator.averaged_stats();
+            self.rows.push(SweepRow { parameters, stats });
+            }
+
+        Ok(())
+        }
+
+    /** `disjoint_config` getter. */
+    #[inline]
+    pub const fn disjoint_config(&self) -> &DisjointConfig
+        { &self.disjoint_config }
+    /** `parameters` getter. */
+    #[inline]
+    pub fn parameters(&self) -> &[Parameter]
+        { &self.parameters }
+    /** `rows` getter. */
+    #[inline]
+    pub fn rows(&self) -> &[SweepRow]
+        { &self.rows }
+
+    /** Show the result table, with the main averaged statistics. */
+    pub fn show(&self) {
+        /* Columns of the table */
+        let mut headers: Vec<_> = self.parameters.iter()
+            .map(|parameter| parameter.name.clone())
+            .collect();
+        headers.extend([
+            "avg route len",
+            "avg routes per ant",
+            "avg best length",
+            "avg cycles run"
+            ].map(String::from));
+
+        /* Cells of the table */
+        let cells: Vec<Vec<_>> = self.rows.iter()
+            .map(|SweepRow { parameters, stats }| {
+                let mut row: Vec<_> = self.parameters.iter()
+                    .map(|parameter| show_value(&parameters[parameter.name()]))
+                    .collect();
+                row.extend([
+                    format!("{:.4}", stats.avg_route_len()),
+                    format!("{:.4}", stats.avg_completed_routes()),
+                    stats.avg_best_route_length().map_or_else(|| String::from("none"), |length| format!("{length:.4}")),
+                    format!("{:.2}", stats.avg_cycles_run())
+                    ]);
+                row
+                })
+            .collect();
+
+        /* Fit the columns to their widest cells */
+        let widths: Vec<_> = headers.iter()
+            .enumerate()
+            .map(|(column, header)| cells.iter()
+                .map(|row| row[column].len())
+                .fold(header.len(), usize::max)
+                )
+            .collect();
+        let show_row = |row: &[String]| {
+            let mut line = String::from("|");
+            for (cell, width) in row.iter().zip(&widths) {
+                _ = write!(line, " {cell:>width$} |");
+                }
+            line
+            };
+
+        /* Print the table */
+        println!("o> ------------ SWEEP ------------ <o");
+        println!("{}", show_row(&headers));
+        for row in &cells {
+            println!("{}", show_row(row));
+            }
+        println!("o> ------------------------------- <o");
+        }
+
+    /** Write the result table to file. */
+    pub fn write_to_file(&self, path: &Path) -> Result<(), SweepError> {
+        /* File writer handle */
+        let file = File::create(path)?;
+
+        /* Try writing the table to the file */
+        to_writer_pretty(file, &self.rows)?;
+
+        /* Write information */
+        info!("Sweep table saved in '{}'", path.display());
+
+        Ok(())
+        }
+    }
+
+/** **Technical part** - helper function, for overriding the configuration's fields with the parameters' values. */
+pub(crate) fn configure(config: &Config, parameters: &BTreeMap<String, Value>) -> Result<Config, SweepError> {
+    let mut fields: Map<_, _> = from_value(to_value(config)?)?;
+    for (name, value) in parameters {
+        fields.insert(name.clone(), value.clone());
+        }
+
+    Ok(from_value(Value::Object(fields))?)
+    }
+
+/** **Technical part** - helper function, for parsing a single value - numbers, booleans, and `null` as JSON, `none` as `null`, and anything else as a string. */
+fn parse_value(value: &str) -> Value {
+    let value = value.trim();
+    match value {
+        "none" => Value::Null,
+        _ => serde_json::from_str(value)
+            .unwrap_or_else(|_| Value::String(value.to_owned()))
+        }
+    }
+
+/** **Technical part** - helper function, for parsing an inclusive range - integer, if all bounds are integers. */
+fn parse_range(start: &str, end: &str, step: &str) -> Option<Box<[Value]>> {
+    let (start, end, step) = (start.trim(), end.trim(), step.trim());
+
+    /* Integer range */
+    if let (Ok(start), Ok(end), Ok(step)) = (start.parse::<i64>(), end.parse::<i64>(), step.parse::<i64>()) {
+        if step <= 0 || end < start {
+            return None;
+            }
+        return Some((start ..= end)
+            .step_by(step as usize)
+            .map(Value::from)
+            .collect());
+        }
+
+    /* Float range, with the end included, and the values rounded despite the rounding errors */
+    let (start, end, step) = (start.parse::<f64>().ok()?, end.parse::<f64>().ok()?, step.parse::<f64>().ok()?);
+    if ! step.is_finite() || step <= 0.0 || end < start {
+        return None;
+        }
+    let count = ((end - start) / step + 1e-9).floor() as usize;
+    (0 ..= count)
+        .map(|index| Number::from_f64(((start + index as f64 * step) * 1e12).round() / 1e12).map(Value::Number))
+        .collect()
+    }
+
+/** **Technical part** - helper function, for showing the parameters' values. */
+pub(crate) fn show_parameters(parameters: &BTreeMap<String, Value>) -> String {
+    parameters.iter()
+        .map(|(name, value)| format!("{name}={}", show_value(value)))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+
+/** **Technical part** - helper function, for showing a value, strings without quotes. */
+pub(crate) fn show_value(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        _ => value.to_string()
+        }
+    }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inclusive_float_range() {
+        let parameter = Parameter::new("pheromone", "0.5..2:0.5").unwrap();
+        assert_eq!(parameter.values(), [0.5, 1.0, 1.5, 2.0].map(Value::from));
+        }
+
+    #[test]
+    fn float_range_without_rounding_errors() {
+        let parameter = Parameter::new("q0", "0.1..0.3:0.1").unwrap();
+        assert_eq!(parameter.values(), [0.1, 0.2, 0.3].map(Value::from));
+        }
+
+    #[test]
+    fn inclusive_integer_range() {
+        let parameter = Parameter::new("ants", "10..30:10").unwrap();
+        assert_eq!(parameter.values(), [10, 20, 30].map(Value::from));
+
+        let parameter = Parameter::new("ants", "10..35:10").unwrap();
+        assert_eq!(parameter.values(), [10, 20, 30].map(Value::from));
+        }
+
+    #[test]
+    fn invalid_ranges() {
+        for values in ["2..1:1", "1..2:0", "1..2:-1", "1..2", "a..b:1"] {
+            assert!(matches!(Parameter::new("ants", values), Err(SweepError::InvalidValues(_))), "{values}");
+            }
+        }
+
+    #[test]
+    fn value_list() {
+        let parameter = Parameter::new("tau-max", "1, none,roulette,true").unwrap();
+        assert_eq!(parameter.name(), "tau_max");
+        assert_eq!(parameter.values(), [Value::from(1), Value::Null, Value::from("roulette"), Value::from(true)]);
+        }
+    }
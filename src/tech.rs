@@ -13,9 +13,9 @@ use {
         ShowOption,
         ShowSlice
         },
-    std::path::PathBuf,
     core::{
         char::ParseCharError,
+        cmp::Ordering,
         str::FromStr,
         fmt::{
             Write,
@@ -25,21 +25,35 @@ use {
             },
         iter::zip,
         },
+    std::{
+        collections::BTreeMap,
+        path::PathBuf,
+        time::{
+            SystemTime,
+            UNIX_EPOCH
+            }
+        },
     crate::{
         anthill::AntHill,
-        consts::limits::{
-            DISPERSION_LINEAR_RANGE,
-            DISPERSION_EXPONENTIAL_RANGE,
-            DISPERSION_RELATIVE_RANGE
+        consts::{
+            bias,
+            default::*,
+            limits::{
+                DISPERSION_LINEAR_RANGE,
+                DISPERSION_EXPONENTIAL_RANGE,
+                DISPERSION_RELATIVE_RANGE
+                }
             },
         error::ParseActionError,
         utils::{
             Auxil,
             Point,
+            deposit::*,
             disperse::*,
             distance::*,
             preference::*,
-            selection::*
+            selection::*,
+            statistics::*
             },
         world::World
         }
@@ -48,8 +62,9 @@ use {
 
 
 /** **Technical part** - type to represent a point ID. */
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 #[repr(transparent)]
+#[serde(transparent)]
 pub struct Id ( char );
 
 impl Id {
@@ -85,8 +100,9 @@ impl FromStr for Id {
     }
 
 /** **Technical part** - type to represent a route. */
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 #[repr(transparent)]
+#[serde(transparent)]
 pub struct Route {
     inner: String
     }
@@ -112,11 +128,24 @@ impl Route {
         self.inner.len()
         }
 
+    /** Checks whether the route is empty. */
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+        }
+
     /** Checks whether the route contains the ID. */
     pub fn contains(&self, id: &Id) -> bool {
         self.inner.contains(id.get())
         }
 
+    /** Number of moves made along the route. */
+    pub fn hops(&self) -> usize {
+        self.inner.chars()
+            .count()
+            .saturating_sub(1)
+        }
+
     /** Get the first ID in the route. */
     pub fn first(&self) -> Option<Id> {
         self.inner.chars()
@@ -131,6 +160,17 @@ impl Route {
             .map(Id::new)
         }
 
+    /** Iterate over the IDs in the route. */
+    pub fn ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.inner.chars()
+            .map(Id::new)
+        }
+
+    /** Iterate over the edges in the route, as pairs of IDs. */
+    pub fn edges(&self) -> impl Iterator<Item = (Id, Id)> + '_ {
+        zip(self.ids(), self.ids().skip(1))
+        }
+
     /** Push new ID to the route. */
     pub fn push(&mut self, id: Id) {
         self.inner.push(id.get());
@@ -149,24 +189,201 @@ impl Display for Route {
         }
     }
 
-/* **Technical part** - type of next point selection enum. */
-#[derive(Debug, Clone, Copy, Display, ValueEnum)]
+/** **Technical part** - structure for holding the best route found so far, along with the circumstances of it's finding. */
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BestRoute {
+    /** The route itself. */
+    route: Route,
+    /** Route's length in metric units. */
+    length: f64,
+    /** Route's number of moves. */
+    hops: usize,
+    /** Number of the cycle, in which the route was first found. */
+    cycle: usize,
+    /** Number of times the route was taken by ants. */
+    ants: usize
+    }
+
+impl BestRoute {
+    /** Constructor, for the route taken by the first ant. */
+    pub fn new(route: Route, length: f64, cycle: usize) -> Self {
+        let hops = route.hops();
+        Self { route, length, hops, cycle, ants: 1 }
+        }
+
+    /** Count another ant taking the route. */
+    pub fn count_ant(&mut self) {
+        self.ants += 1;
+        }
+
+    /** `route` getter. */
+    #[inline]
+    pub const fn route(&self) -> &Route
+        { &self.route }
+    /** `length` getter. */
+    #[inline]
+    pub const fn length(&self) -> f64
+        { self.length }
+    /** `hops` getter. */
+    #[inline]
+    pub const fn hops(&self) -> usize
+        { self.hops }
+    /** `cycle` getter. */
+    #[inline]
+    pub const fn cycle(&self) -> usize
+        { self.cycle }
+    /** `ants` getter. */
+    #[inline]
+    pub const fn ants(&self) -> usize
+        { self.ants }
+    }
+
+/* **Technical part** - trait implementation for best route printing. */
+impl Display for BestRoute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}, length: {}, hops: {}, cycle: {}, ants: {}", self.route, self.length, self.hops, self.cycle, self.ants)
+        }
+    }
+
+/** **Technical part** - type of next point selection enum. */
+#[derive(Debug, Clone, Copy, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Selection {
     /** Greedy selection method. */
     Greedy,
     /** Random selection method. */
     Random,
     /** Roulette selection method. */
-    Roulette
+    Roulette,
+    /** Pseudo-random proportional selection method. */
+    Proportional
     }
 
 impl Selection {
-    /** Calculates the new index based on the number of decision points, and slice of helper structs. */
-    pub fn calculate(&self, decision_points: usize, auxils: &[Auxil]) -> usize {
+    /** Calculates the new index based on the number of decision points, slice of helper structs, and the greedy choice probability. */
+    pub fn calculate(&self, decision_points: usize, auxils: &[Auxil], q0: f64) -> usize {
         match self {
             Self::Greedy => greedy(),
             Self::Random => randomly(decision_points),
-            Self::Roulette => roulette(decision_points, auxils)
+            Self::Roulette => roulette(decision_points, auxils),
+            Self::Proportional => proportional(decision_points, auxils, q0)
+            }
+        }
+    }
+
+/** **Technical part** - kinds of problems solved by the ants enum. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Problem {
+    /** Ants search for any food source. */
+    Forage,
+    /** Ants visit every point, and return to the anthill - the travelling salesman problem. */
+    Tour
+    }
+
+/** **Technical part** - variants of the ant algorithm enum. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Algorithm {
+    /** Generic algorithm, every ant covers it's route upon reaching the goal. */
+    Generic,
+    /** Ant Colony System, local updates on every move, and global update along the best-so-far route. */
+    #[display("ACS")]
+    Acs,
+    /** MAX-MIN Ant System, only the best ant covers it's route, trails are limited, and reset upon stagnation. */
+    #[display("MMAS")]
+    Mmas,
+    /** Elitist Ant System, the best-so-far route gets an additional weighted cover every cycle. */
+    Elitist,
+    /** Rank-based Ant System, only the top ants of a cycle cover their routes, weighted by their rank. */
+    Rank
+    }
+
+impl Algorithm {
+    /** Checks whether ants' routes are covered only at the end of the cycle. */
+    pub const fn defers_deposit(&self) -> bool {
+        matches!(self, Self::Mmas | Self::Elitist | Self::Rank)
+        }
+    }
+
+/** **Technical part** - kinds of the best route, which gets reinforced enum. */
+#[derive(Debug, Clone, Copy, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Reinforcement {
+    /** The best route of the current cycle. */
+    Iteration,
+    /** The best route found so far. */
+    Global
+    }
+
+/** **Technical part** - rules, which stopped the simulation's run enum. */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Display, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StopReason {
+    /** All the cycles were run. */
+    #[default]
+    Budget,
+    /** All ants were satiated. */
+    Satiated,
+    /** The best route wasn't improved for the set number of cycles. */
+    Patience,
+    /** The pheromones' relative entropy dropped below the threshold. */
+    Entropy
+    }
+
+/** **Technical part** - objectives of the parameter tuning enum. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Objective {
+    /** Shortest median length of the runs' best routes - runs without any route count as infinitely long. */
+    #[display("best length")]
+    BestLength,
+    /** Most satiated ants by the cycle, on average. */
+    Satiated,
+    /** Fewest cycles run, on average - meaningful with the stopping rules. */
+    Cycles
+    }
+
+impl Objective {
+    /** Calculates the objective's value of the batch's runs - takes the cycle for the satiated ants, counted from 1, the last one if not set. */
+    pub fn value(&self, stats: &[Stats], cycle: Option<usize>) -> f64 {
+        match self {
+            Self::BestLength => {
+                let mut lengths: Vec<_> = stats.iter()
+                    .map(|stat| stat.best_route.as_ref().map_or(f64::INFINITY, BestRoute::length))
+                    .collect();
+                lengths.sort_unstable_by(f64::total_cmp);
+                match lengths.as_slice() {
+                    [] => f64::INFINITY,
+                    sorted => quantile(sorted, 0.5)
+                    }
+                },
+            Self::Satiated => {
+                let last = stats.iter()
+                    .map(Stats::cycles_run)
+                    .max()
+                    .unwrap_or_default();
+                let index = cycle.map_or(last, |cycle| cycle.min(last))
+                    .saturating_sub(1);
+                let ants: Vec<_> = stats.iter()
+                    .map(|stat| satiated_in_phase(&stat.ants_per_phase, index) as f64)
+                    .collect();
+                mean(&ants)
+                },
+            Self::Cycles => {
+                let cycles: Vec<_> = stats.iter()
+                    .map(|stat| stat.cycles_run() as f64)
+                    .collect();
+                mean(&cycles)
+                }
+            }
+        }
+
+    /** Compares two values of the objective, the better one is ordered first. */
+    pub fn compare(&self, a: f64, b: f64) -> Ordering {
+        match self {
+            Self::BestLength | Self::Cycles => a.total_cmp(&b),
+            Self::Satiated => b.total_cmp(&a)
             }
         }
     }
@@ -177,7 +394,8 @@ impl Selection {
 - F - Food
 - D - Distance
 */
-#[derive(Debug, Clone, Copy, Display, ValueEnum)]
+#[derive(Debug, Clone, Copy, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Preference {
     /** Point prefrence calculation for distance. */
     Distance,
@@ -196,22 +414,62 @@ pub enum Preference {
     }
 
 impl Preference {
-    /** Calculates the point preference based on the point's values, current coordinates, and the metric. */
-    pub fn calculate(&self, point: &Point, x: i16, y: i16, metric: Metric) -> f64 {
+    /** Calculates the point preference based on the point's values, pheromones leading to it, current coordinates, the metric, and the factors' exponents. */
+    pub fn calculate(&self, point: &Point, phero: f64, x: i16, y: i16, metric: Metric, exponents: Exponents) -> f64 {
         match self {
-            Self::Distance => distance(point, x, y, metric),
-            Self::Pheromone => pheromone(point),
-            Self::Food => food(point),
-            Self::PD => phero_dist(point, x, y, metric),
-            Self::FD => food_dist(point, x, y, metric),
-            Self::PF => phero_food(point),
-            Self::PFD => phero_food_dist(point, x, y, metric),
+            Self::Distance => distance(point, x, y, metric, exponents),
+            Self::Pheromone => pheromone(phero, exponents),
+            Self::Food => food(point, exponents),
+            Self::PD => phero_dist(point, phero, x, y, metric, exponents),
+            Self::FD => food_dist(point, x, y, metric, exponents),
+            Self::PF => phero_food(point, phero, exponents),
+            Self::PFD => phero_food_dist(point, phero, x, y, metric, exponents),
+            }
+        }
+    }
+
+/** **Technical part** - structure for holding exponents of the point preference's factors. */
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct Exponents {
+    /** Pheromone factor's exponent. */
+    pub alpha: f64,
+    /** Distance factor's exponent. */
+    pub beta: f64,
+    /** Food factor's exponent. */
+    pub gamma: f64
+    }
+
+/** **Technical part** - trait implementation for the neutral exponents. */
+impl Default for Exponents {
+    fn default() -> Self {
+        Self {
+            alpha: bias::NEUTRAL,
+            beta: bias::NEUTRAL,
+            gamma: bias::NEUTRAL
             }
         }
     }
 
+/* **Technical part** - trait implementation for exponents printing. */
+impl Display for Exponents {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "alpha: {}, beta: {}, gamma: {}", self.alpha, self.beta, self.gamma)
+        }
+    }
+
+/** **Technical part** - places where pheromones are laid out enum. */
+#[derive(Debug, Clone, Copy, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PheromoneModel {
+    /** Pheromones are laid out on the points. */
+    Point,
+    /** Pheromones are laid out on the edges between points. */
+    Edge
+    }
+
 /** **Technical part** - types of metrics for distance calculation enum. */
-#[derive(Debug, Clone, Copy, Display, ValueEnum)]
+#[derive(Debug, Clone, Copy, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Metric {
     /** Chebyshev metric distance calculation. */
     Chebyshev,
@@ -233,7 +491,8 @@ impl Metric {
     }
 
 /** **Technical part** - types of pheromone dispersion enum. */
-#[derive(Debug, Clone, Copy, Display, ValueEnum)]
+#[derive(Debug, Clone, Copy, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Dispersion {
     /** Linear dispersion calculation. */
     Linear,
@@ -253,24 +512,48 @@ impl Dispersion {
             }
         }
 
-    /** Calculates the dispersion based on the point's values, and the coefficient */
-    pub fn calculate(&self, point: &Point, factor: f64) -> f64 {
+    /** Calculates the dispersion based on the amount of pheromones, and the coefficient */
+    pub fn calculate(&self, pheromone: f64, factor: f64) -> f64 {
+        match self {
+            Self::Linear => linear(pheromone, factor),
+            Self::Exponential => exponential(pheromone, factor),
+            Self::Relative => relative(pheromone, factor)
+            }
+        }
+    }
+
+/** **Technical part** - ways of calculating amount of deposited pheromones enum. */
+#[derive(Debug, Clone, Copy, Display, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Deposit {
+    /** The pheromone strength is deposited as is. */
+    Constant,
+    /** The pheromone strength is divided by the route's length in metric units. */
+    Distance,
+    /** The pheromone strength is divided by the route's number of moves. */
+    Hops
+    }
+
+impl Deposit {
+    /** Calculates the deposit based on the pheromone strength, the route, and the world it was taken in. */
+    pub fn calculate(&self, pheromone: f64, route: &Route, world: &World) -> f64 {
         match self {
-            Self::Linear => linear(point, factor),
-            Self::Exponential => exponential(point, factor),
-            Self::Relative => relative(point, factor)
+            Self::Constant => constant(pheromone),
+            Self::Distance => inverse(pheromone, world.route_length(route)),
+            Self::Hops => inverse(pheromone, route.hops() as f64)
             }
         }
     }
 
 /** **Technical part** - cycle action. */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Action {
     /** Cycle at which to make the action. */
     pub cycle: usize,
     /** Point ID at which to add the food. */
     pub id: Id,
     /** Amount of food to add. */
+    #[serde(rename = "food")]
     pub food_amount: u32
     }
 
@@ -304,7 +587,8 @@ impl FromStr for Action {
     }
 
 /** **Technical part** - structure for holding, and printing simulation's configuration. */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
 pub struct Config {
     /** Number of cycles. */
     pub cycles: usize,
@@ -318,21 +602,77 @@ pub struct Config {
     pub rate: u32,
     /** Whether ants return, after reaching food. */
     pub returns: bool,
+    /** Problem solved by the ants. */
+    pub problem: Problem,
+    /** Possible target point, the only goal of the ants - replaces food sources. */
+    pub target: Option<Id>,
     /** Point selection method. */
     pub select: Selection,
     /** Point prefrence calculation method. */
     pub preference: Preference,
     /** Distance calculation metric. */
     pub metric: Metric,
+    /** Pheromone factor's exponent. */
+    pub alpha: f64,
+    /** Distance factor's exponent. */
+    pub beta: f64,
+    /** Food factor's exponent. */
+    pub gamma: f64,
     /** Possible dispersion behaviour. */
     pub dispersion: Option<Dispersion>,
     /** Possible dispersion coefficient. */
+    #[serde(with = "unknown_float")]
     pub factor: f64,
+    /** Places where pheromones are laid out. */
+    pub pheromone_model: PheromoneModel,
+    /** Pheromone deposit calculation method. */
+    pub deposit: Deposit,
+    /** Variant of the algorithm. */
+    pub algorithm: Algorithm,
+    /** Probability of the greedy choice, for the proportional selection. */
+    pub q0: f64,
+    /** Local pheromone update coefficient, for the ACS. */
+    pub xi: f64,
+    /** Initial amount of pheromones, for the ACS. */
+    pub tau0: f64,
+    /** Lower limit of pheromones on a trail, for the MMAS. */
+    pub tau_min: f64,
+    /** Upper limit, and initial amount of pheromones on a trail, for the MMAS. */
+    pub tau_max: f64,
+    /** Route reinforced at the end of each cycle, for the MMAS. */
+    pub reinforcement: Reinforcement,
+    /** Number of cycles without improvement, after which trails are reset, for the MMAS - zero disables the reset. */
+    pub stagnation: usize,
+    /** Weight of the best-so-far route's additional cover, for the elitist variant. */
+    pub elite_weight: f64,
+    /** Number of top ants covering their routes, for the rank-based variant. */
+    pub rank_count: usize,
+    /** Whether the run stops, once all ants are satiated. */
+    pub stop_satiated: bool,
+    /** Possible number of cycles without improvement of the best route, after which the run stops. */
+    pub patience: Option<usize>,
+    /** Possible relative entropy of pheromones, below which the run stops. */
+    pub entropy: Option<f64>,
     /** Possible random number generator seed. */
     pub seed: Option<u64>
     }
 
 impl Config {
+    /** Creates a builder, starting from the default settings. */
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+        }
+
+    /** `exponents` getter. */
+    #[inline]
+    pub const fn exponents(&self) -> Exponents {
+        Exponents {
+            alpha: self.alpha,
+            beta: self.beta,
+            gamma: self.gamma
+            }
+        }
+
     /** Show operation for the settings. */
     pub fn show(&self) {
         println!(
@@ -343,45 +683,331 @@ impl Config {
 | decision points: {}
 | consumtion rate: {}
 |         returns: {}
+|         problem: {}
+|          target: {}
 |       selection: {}
 |     calculation: {}
 |          metric: {}
+|           alpha: {}
+|            beta: {}
+|           gamma: {}
 |      dispersion: {}
 |          factor: {}
+| pheromone model: {}
+|         deposit: {}
+|       algorithm: {}
+|              q0: {}
+|              xi: {}
+|            tau0: {}
+|         tau min: {}
+|         tau max: {}
+|   reinforcement: {}
+|      stagnation: {}
+|    elite weight: {}
+|      rank count: {}
+|   stop satiated: {}
+|        patience: {}
+|         entropy: {}
 |            seed: {}
 o> -------------------------- <o",
             self.cycles, self.ants, self.pheromone, self.decision,
-            self.rate, self.returns,
+            self.rate, self.returns, self.problem, self.target.show_or_none(),
             self.select, self.preference, self.metric,
+            self.alpha, self.beta, self.gamma,
             self.dispersion.show_or_none(), self.factor,
+            self.pheromone_model, self.deposit,
+            self.algorithm, self.q0, self.xi, self.tau0,
+            self.tau_min, self.tau_max, self.reinforcement, self.stagnation,
+            self.elite_weight, self.rank_count,
+            self.stop_satiated, self.patience.show_or_none(), self.entropy.show_or_none(),
             self.seed.show_or_none()
             );
         }
     }
 
-/** **Technical part** - structure for grouping of simulation's configuration of outer actions. */
-#[derive(Debug, Clone)]
-pub struct ActionsConfig {
-    /** Whether simulation time should be displayed. */
-    pub counts_time: bool,
-    /** Possible path for the statistics' output file. */
-    pub output_path: Option<PathBuf>
+/** **Technical part** - trait implementation for the default settings. */
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            cycles: NUM_OF_CYCLES as usize,
+            ants: NUM_OF_ANTS as usize,
+            pheromone: PHERO_STRENGTH,
+            decision: NUM_OF_DECISION_POINTS as usize,
+            rate: CONSUME_RATE,
+            returns: RETURN_BEHAVIOUR,
+            problem: PROBLEM,
+            target: None,
+            select: SELECT_METHOD,
+            preference: PREFERENCE_METHOD,
+            metric: METRIC,
+            alpha: EXPONENT,
+            beta: EXPONENT,
+            gamma: EXPONENT,
+            dispersion: None,
+            factor: bias::UNKOWN,
+            pheromone_model: PHEROMONE_MODEL,
+            deposit: DEPOSIT_METHOD,
+            algorithm: ALGORITHM,
+            q0: GREEDY_PROBABILITY,
+            xi: LOCAL_UPDATE_COEFFICIENT,
+            tau0: INITIAL_PHEROMONE,
+            tau_min: TRAIL_MIN,
+            tau_max: TRAIL_MAX,
+            reinforcement: REINFORCEMENT,
+            stagnation: STAGNATION_CYCLES,
+            elite_weight: ELITE_WEIGHT,
+            rank_count: RANK_COUNT,
+            stop_satiated: STOP_SATIATED,
+            patience: None,
+            entropy: None,
+            seed: None
+            }
+        }
+    }
+
+/** **Technical part** - builder for the `Config` structure, starting from the default settings. */
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    /** Configuration under construction. */
+    config: Config
+    }
+
+impl ConfigBuilder {
+    /** Sets number of cycles. */
+    pub fn cycles(mut self, cycles: usize) -> Self {
+        self.config.cycles = cycles;
+        self
+        }
+
+    /** Sets number of ants. */
+    pub fn ants(mut self, ants: usize) -> Self {
+        self.config.ants = ants;
+        self
+        }
+
+    /** Sets the strength of pheromones. */
+    pub fn pheromone(mut self, pheromone: f64) -> Self {
+        self.config.pheromone = pheromone;
+        self
+        }
+
+    /** Sets the number of decision points. */
+    pub fn decision(mut self, decision: usize) -> Self {
+        self.config.decision = decision;
+        self
+        }
+
+    /** Sets how much food is consumed. */
+    pub fn rate(mut self, rate: u32) -> Self {
+        self.config.rate = rate;
+        self
+        }
+
+    /** Sets whether ants return to the anthill. */
+    pub fn returns(mut self, returns: bool) -> Self {
+        self.config.returns = returns;
+        self
+        }
+
+    /** Sets the problem solved by the ants. */
+    pub fn problem(mut self, problem: Problem) -> Self {
+        self.config.problem = problem;
+        self
+        }
+
+    /** Sets the target point, the only goal of the ants. */
+    pub fn target(mut self, target: Id) -> Self {
+        self.config.target = Some(target);
+        self
+        }
+
+    /** Sets how points are selected. */
+    pub fn select(mut self, select: Selection) -> Self {
+        self.config.select = select;
+        self
+        }
+
+    /** Sets how the point preference is calculated. */
+    pub fn preference(mut self, preference: Preference) -> Self {
+        self.config.preference = preference;
+        self
+        }
+
+    /** Sets how the distance between points is calculated. */
+    pub fn metric(mut self, metric: Metric) -> Self {
+        self.config.metric = metric;
+        self
+        }
+
+    /** Sets the exponents of the pheromone, distance, and food factors. */
+    pub fn exponents(mut self, alpha: f64, beta: f64, gamma: f64) -> Self {
+        self.config.alpha = alpha;
+        self.config.beta = beta;
+        self.config.gamma = gamma;
+        self
+        }
+
+    /** Sets the dispersion mode, with it's coefficient. */
+    pub fn dispersion(mut self, dispersion: Dispersion, factor: f64) -> Self {
+        self.config.dispersion = Some(dispersion);
+        self.config.factor = factor;
+        self
+        }
+
+    /** Sets where pheromones are laid out. */
+    pub fn pheromone_model(mut self, pheromone_model: PheromoneModel) -> Self {
+        self.config.pheromone_model = pheromone_model;
+        self
+        }
+
+    /** Sets how the amount of deposited pheromones is calculated. */
+    pub fn deposit(mut self, deposit: Deposit) -> Self {
+        self.config.deposit = deposit;
+        self
+        }
+
+    /** Sets the variant of the algorithm. */
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.config.algorithm = algorithm;
+        self
+        }
+
+    /** Sets the probability of the greedy choice, for the proportional selection. */
+    pub fn q0(mut self, q0: f64) -> Self {
+        self.config.q0 = q0;
+        self
+        }
+
+    /** Sets the local pheromone update coefficient, for the ACS. */
+    pub fn xi(mut self, xi: f64) -> Self {
+        self.config.xi = xi;
+        self
+        }
+
+    /** Sets the initial amount of pheromones, for the ACS. */
+    pub fn tau0(mut self, tau0: f64) -> Self {
+        self.config.tau0 = tau0;
+        self
+        }
+
+    /** Sets the limits of pheromones on a trail, for the MMAS. */
+    pub fn trail_limits(mut self, tau_min: f64, tau_max: f64) -> Self {
+        self.config.tau_min = tau_min;
+        self.config.tau_max = tau_max;
+        self
+        }
+
+    /** Sets the route reinforced at the end of each cycle, for the MMAS. */
+    pub fn reinforcement(mut self, reinforcement: Reinforcement) -> Self {
+        self.config.reinforcement = reinforcement;
+        self
+        }
+
+    /** Sets the number of cycles without improvement, after which trails are reset, for the MMAS. */
+    pub fn stagnation(mut self, stagnation: usize) -> Self {
+        self.config.stagnation = stagnation;
+        self
+        }
+
+    /** Sets the weight of the best-so-far route's additional cover, for the elitist variant. */
+    pub fn elite_weight(mut self, elite_weight: f64) -> Self {
+        self.config.elite_weight = elite_weight;
+        self
+        }
+
+    /** Sets the number of top ants covering their routes, for the rank-based variant. */
+    pub fn rank_count(mut self, rank_count: usize) -> Self {
+        self.config.rank_count = rank_count;
+        self
+        }
+
+    /** Sets whether the run stops, once all ants are satiated. */
+    pub fn stop_satiated(mut self, stop_satiated: bool) -> Self {
+        self.config.stop_satiated = stop_satiated;
+        self
+        }
+
+    /** Sets the number of cycles without improvement of the best route, after which the run stops. */
+    pub fn patience(mut self, patience: usize) -> Self {
+        self.config.patience = Some(patience);
+        self
+        }
+
+    /** Sets the relative entropy of pheromones, below which the run stops. */
+    pub fn entropy(mut self, entropy: f64) -> Self {
+        self.config.entropy = Some(entropy);
+        self
+        }
+
+    /** Sets the random number generator seed. */
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+        }
+
+    /** Finishes the building, the settings are asserted only once passed to the `Simulator`. */
+    pub fn build(self) -> Config {
+        self.config
+        }
     }
 
 /** **Technical part** - structure for grouping of disjoint simulation's configuration. */
 #[derive(Debug, Clone)]
 pub struct DisjointConfig {
-    /** Whether simulation time should be displayed. */
+    /** Whether logging should be turned off. */
     pub no_logging: bool,
-    /** Possible path for the statistics' output file. */
+    /** Whether per-cycle series should be recorded. */
+    pub records_series: bool,
+    /** Number of simulation's repetitions. */
     pub batch_size: usize,
+    /** Number of threads running the repetitions. */
+    pub jobs: usize,
+    /** Possible number of the only run to simulate, counted from 0. */
+    pub run_index: Option<usize>,
+    /** World grid, the first point is the anthill. */
     pub grid: Vec<Point>,
-    pub actions: Vec<Action>
+    /** Food changes made during the runtime. */
+    pub actions: Vec<Action>,
+    /** Possible checkpoints' settings. */
+    pub checkpointing: Option<Checkpointing>
+    }
+
+/** **Technical part** - structure for holding the checkpoints' settings. */
+#[derive(Debug, Clone)]
+pub struct Checkpointing {
+    /** Path of the checkpoint file. */
+    pub path: PathBuf,
+    /** Number of cycles between the checkpoints - zero disables. */
+    pub cycles: usize,
+    /** Number of finished runs between the checkpoints - zero disables. */
+    pub runs: usize
+    }
+
+/** **Technical part** - trait implementation for the default settings. */
+impl Default for DisjointConfig {
+    fn default() -> Self {
+        Self {
+            no_logging: QUIET,
+            records_series: SERIES,
+            batch_size: BATCH_SIZE as usize,
+            jobs: JOBS as usize,
+            run_index: None,
+            grid: Vec::from(GRID),
+            actions: Vec::new(),
+            checkpointing: None
+            }
+        }
     }
 
 /** **Technical part** - structure for holding statistics of simulation's run, and operations needed for saving this data. */
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Stats {
+    /** Number of the run in the batch, counted from 0. */
+    #[serde(default)]
+    run: usize,
+    /** Seed of the run's random stream. */
+    #[serde(default)]
+    seed: u64,
     /** Whether all ants have finished their routes. */
     completed: bool,
     /** Final pheromone strengths for points in declaration order. */
@@ -391,22 +1017,133 @@ pub struct Stats {
     /** Number of satiated ants for each cycle. */
     ants_per_phase: Box<[usize]>,
     /** Average number of routes per ant. */
-    completed_routes: f64
+    completed_routes: f64,
+    /** Exponents of the point preference's factors. */
+    #[serde(default)]
+    exponents: Exponents,
+    /** Final pheromone strengths for covered edges, labeled with their points' IDs - empty for the point model. */
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    edge_pheromone_strengths: BTreeMap<String, f64>,
+    /** Shortest route found during the run, in metric units. */
+    #[serde(default)]
+    best_route: Option<BestRoute>,
+    /** Route with the fewest hops found during the run. */
+    #[serde(default)]
+    fewest_hops_route: Option<BestRoute>,
+    /** Length of the shortest route finished in each cycle. */
+    #[serde(default)]
+    best_length_per_phase: Box<[Option<f64>]>,
+    /** Average length of routes finished in each cycle. */
+    #[serde(default)]
+    average_length_per_phase: Box<[Option<f64>]>,
+    /** Possible state of the simulation at the end of each cycle. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    series: Option<Box<[PhaseSnapshot]>>,
+    /** Number of times each distinct route was finished by ants. */
+    #[serde(default)]
+    route_histogram: BTreeMap<Route, usize>,
+    /** Rule, which stopped the run. */
+    #[serde(default)]
+    stop_reason: StopReason
     }
 
 impl Stats {
-    /** Constructor. */
-    pub fn new<T>(ant_hill: &AntHill, world: &World, ants_per_phase: T) -> Self
+    /** Constructor, takes the run's number, and seed, the number of satiated ants, the shortest, and average finished route length, and possibly the snapshot for each cycle, and the rule, which stopped the run. */
+    pub fn new<T>(ant_hill: &AntHill, world: &World, run_values: (usize, u64), ants_per_phase: T, lengths_per_phase: &[(Option<f64>, Option<f64>)], series: Option<Vec<PhaseSnapshot>>, stop_reason: StopReason) -> Self
     where T: Into<Box<[usize]>> {
+        let (best_length_per_phase, average_length_per_phase) = lengths_per_phase.iter()
+            .copied()
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        let (run, seed) = run_values;
+
         Self {
+            run,
+            seed,
             completed: ant_hill.has_all_ants_satiated(),
             pheromone_strengths: world.pheromones_per_point(),
             average_route_len: ant_hill.average_route_length(),
             ants_per_phase: ants_per_phase.into(),
-            completed_routes: ant_hill.average_routes_count()
+            completed_routes: ant_hill.average_routes_count(),
+            exponents: world.exponents(),
+            edge_pheromone_strengths: world.pheromones_per_edge(),
+            best_route: ant_hill.best_route().cloned(),
+            fewest_hops_route: ant_hill.fewest_hops().cloned(),
+            best_length_per_phase: best_length_per_phase.into(),
+            average_length_per_phase: average_length_per_phase.into(),
+            series: series.map(Vec::into_boxed_slice),
+            route_histogram: ant_hill.route_histogram().clone(),
+            stop_reason
             }
         }
 
+    /** `run` getter. */
+    #[inline]
+    pub const fn run(&self) -> usize
+        { self.run }
+    /** `seed` getter. */
+    #[inline]
+    pub const fn seed(&self) -> u64
+        { self.seed }
+    /** `completed` getter. */
+    #[inline]
+    pub const fn completed(&self) -> bool
+        { self.completed }
+    /** `pheromone_strengths` getter. */
+    #[inline]
+    pub fn pheromone_strengths(&self) -> &[f64]
+        { &self.pheromone_strengths }
+    /** `average_route_len` getter. */
+    #[inline]
+    pub const fn average_route_len(&self) -> f64
+        { self.average_route_len }
+    /** `ants_per_phase` getter. */
+    #[inline]
+    pub fn ants_per_phase(&self) -> &[usize]
+        { &self.ants_per_phase }
+    /** `completed_routes` getter. */
+    #[inline]
+    pub const fn completed_routes(&self) -> f64
+        { self.completed_routes }
+    /** `exponents` getter. */
+    #[inline]
+    pub const fn exponents(&self) -> Exponents
+        { self.exponents }
+    /** `edge_pheromone_strengths` getter. */
+    #[inline]
+    pub const fn edge_pheromone_strengths(&self) -> &BTreeMap<String, f64>
+        { &self.edge_pheromone_strengths }
+    /** `best_route` getter. */
+    #[inline]
+    pub const fn best_route(&self) -> Option<&BestRoute>
+        { self.best_route.as_ref() }
+    /** `fewest_hops_route` getter. */
+    #[inline]
+    pub const fn fewest_hops_route(&self) -> Option<&BestRoute>
+        { self.fewest_hops_route.as_ref() }
+    /** `best_length_per_phase` getter. */
+    #[inline]
+    pub fn best_length_per_phase(&self) -> &[Option<f64>]
+        { &self.best_length_per_phase }
+    /** `average_length_per_phase` getter. */
+    #[inline]
+    pub fn average_length_per_phase(&self) -> &[Option<f64>]
+        { &self.average_length_per_phase }
+    /** `series` getter. */
+    #[inline]
+    pub fn series(&self) -> Option<&[PhaseSnapshot]>
+        { self.series.as_deref() }
+    /** `route_histogram` getter. */
+    #[inline]
+    pub const fn route_histogram(&self) -> &BTreeMap<Route, usize>
+        { &self.route_histogram }
+    /** `stop_reason` getter. */
+    #[inline]
+    pub const fn stop_reason(&self) -> StopReason
+        { self.stop_reason }
+    /** Number of cycles run. */
+    #[inline]
+    pub const fn cycles_run(&self) -> usize
+        { self.ants_per_phase.len() }
     /** `pheromone_per_route` getter. */
     pub fn pheromone_per_route(&self) -> Box<[f64]> {
         self.pheromone_strengths.iter()
@@ -418,25 +1155,310 @@ impl Stats {
     pub fn show(&self) {
         println!(
 "o> --------- STATISTICS --------- <o
+|                     run: {}
+|                run seed: {}
+|              stopped by: {}
+|              cycles run: {}
 |        all reached goal: {}
 |    pheromones per point: {}
 |    average route length: {}
 | satiated ants per phase: {}
 |  average routes per ant: {}
 |    pheromones per route: {}
-o> ------------------------------ <o",
+|               exponents: {}
+|              best route: {}
+|       fewest hops route: {}
+|              top routes: {}
+|   best length per phase: {}
+|    avg length per phase: {}
+{}o> ------------------------------ <o",
+            self.run,
+            self.seed,
+            self.stop_reason,
+            self.cycles_run(),
             self.completed,
             self.pheromone_strengths.show_slice(),
             self.average_route_len,
             self.ants_per_phase.show_slice(),
             self.completed_routes,
-            self.pheromone_per_route().show_slice()
+            self.pheromone_per_route().show_slice(),
+            self.exponents,
+            self.best_route.show_or_none(),
+            self.fewest_hops_route.show_or_none(),
+            show_top_routes(&self.route_histogram),
+            show_lengths(&self.best_length_per_phase),
+            show_lengths(&self.average_length_per_phase),
+            show_edges(&self.edge_pheromone_strengths)
             );
         }
     }
 
+/** **Technical part** - structure for holding the description of the simulation, which produced the statistics. */
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Manifest {
+    /** Version of the crate. */
+    version: String,
+    /** UNIX timestamp of saving, in seconds. */
+    timestamp: u64,
+    /** Simulation's configuration. */
+    config: Config,
+    /** World grid, with points' initial food. */
+    grid: Vec<Point>,
+    /** Food changes made during the runtime. */
+    actions: Vec<Action>,
+    /** Number or repetitions. */
+    batch_size: usize,
+    /** Whether per-cycle series were recorded. */
+    records_series: bool,
+    /** Seed of the batch's random streams. */
+    seed: u64
+    }
+
+impl Manifest {
+    /** Constructor, takes the configuration, the world grid with the food changes, the number of repetitions, and whether series were recorded - stamped with the current time. */
+    pub fn new(config: Config, world_values: (Vec<Point>, Vec<Action>), batch_size: usize, records_series: bool) -> Self {
+        let (grid, actions) = world_values;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        Self {
+            version: String::from(env!("CARGO_PKG_VERSION")),
+            timestamp,
+            seed: config.seed.unwrap_or_default(),
+            config,
+            grid,
+            actions,
+            batch_size,
+            records_series
+            }
+        }
+
+    /** `version` getter. */
+    #[inline]
+    pub fn version(&self) -> &str
+        { &self.version }
+    /** `timestamp` getter. */
+    #[inline]
+    pub const fn timestamp(&self) -> u64
+        { self.timestamp }
+    /** `config` getter. */
+    #[inline]
+    pub const fn config(&self) -> &Config
+        { &self.config }
+    /** `grid` getter. */
+    #[inline]
+    pub fn grid(&self) -> &[Point]
+        { &self.grid }
+    /** `actions` getter. */
+    #[inline]
+    pub fn actions(&self) -> &[Action]
+        { &self.actions }
+    /** `batch_size` getter. */
+    #[inline]
+    pub const fn batch_size(&self) -> usize
+        { self.batch_size }
+    /** `records_series` getter. */
+    #[inline]
+    pub const fn records_series(&self) -> bool
+        { self.records_series }
+    /** `seed` getter. */
+    #[inline]
+    pub const fn seed(&self) -> u64
+        { self.seed }
+    }
+
+/** **Technical part** - structure for holding a saved run - it's statistics, with the manifest, missing in the files written by older versions. */
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(from = "StoredRecord")]
+pub struct Record {
+    /** Possible description of the simulation. */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manifest: Option<Manifest>,
+    /** Statistics of the run. */
+    stats: Stats
+    }
+
+impl Record {
+    /** Constructor. */
+    pub const fn new(manifest: Option<Manifest>, stats: Stats) -> Self
+        { Self { manifest, stats } }
+
+    /** `manifest` getter. */
+    #[inline]
+    pub const fn manifest(&self) -> Option<&Manifest>
+        { self.manifest.as_ref() }
+    /** `stats` getter. */
+    #[inline]
+    pub const fn stats(&self) -> &Stats
+        { &self.stats }
+    /** Take the statistics. */
+    #[inline]
+    pub fn into_stats(self) -> Stats
+        { self.stats }
+    }
+
+/** **Technical part** - saved run, as found in the file - wrapped with the manifest, or the bare statistics of the older versions. */
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredRecord {
+    /** Statistics wrapped with the manifest. */
+    Wrapped {
+        #[serde(default)]
+        manifest: Option<Box<Manifest>>,
+        stats: Stats
+        },
+    /** Bare statistics. */
+    Bare(Stats)
+    }
+
+/** **Technical part** - trait implementation for reading the older files. */
+impl From<StoredRecord> for Record {
+    fn from(record: StoredRecord) -> Self {
+        match record {
+            StoredRecord::Wrapped { manifest, stats } => Self { manifest: manifest.map(|manifest| *manifest), stats },
+            StoredRecord::Bare(stats) => Self { manifest: None, stats }
+            }
+        }
+    }
+
+/** **Technical part** - structure for holding the state of the simulation at the end of a cycle. */
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PhaseSnapshot {
+    /** Pheromone strengths for points in declaration order. */
+    pheromone_strengths: Box<[f64]>,
+    /** Food amounts for points in declaration order. */
+    food_amounts: Box<[u32]>,
+    /** Average route length of ants, which finished in the cycle. */
+    average_route_len: Option<f64>
+    }
+
+impl PhaseSnapshot {
+    /** Constructor. */
+    pub fn new(ant_hill: &AntHill, world: &World) -> Self {
+        Self {
+            pheromone_strengths: world.pheromones_per_point(),
+            food_amounts: world.food_per_point(),
+            average_route_len: ant_hill.cycle_average_route_length()
+            }
+        }
+
+    /** `pheromone_strengths` getter. */
+    #[inline]
+    pub fn pheromone_strengths(&self) -> &[f64]
+        { &self.pheromone_strengths }
+    /** `food_amounts` getter. */
+    #[inline]
+    pub fn food_amounts(&self) -> &[u32]
+        { &self.food_amounts }
+    /** `average_route_len` getter. */
+    #[inline]
+    pub const fn average_route_len(&self) -> Option<f64>
+        { self.average_route_len }
+    }
+
+/** **Technical part** - structure for holding the spread of a value across the batch. */
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct Spread {
+    /** Arithmetic mean. */
+    mean: f64,
+    /** Sample standard deviation. */
+    std_dev: f64,
+    /** Smallest value. */
+    min: f64,
+    /** Largest value. */
+    max: f64,
+    /** Median value. */
+    median: f64,
+    /** Lower, and upper quartile. */
+    quartiles: (f64, f64),
+    /** Lower, and upper bound of the mean's 95% confidence interval, from Student's t distribution. */
+    confidence_interval: (f64, f64)
+    }
+
+impl Spread {
+    /** Spread of an empty sample, with all values unknown. */
+    const UNKNOWN: Self = Self {
+        mean: bias::UNKOWN,
+        std_dev: bias::UNKOWN,
+        min: bias::UNKOWN,
+        max: bias::UNKOWN,
+        median: bias::UNKOWN,
+        quartiles: (bias::UNKOWN, bias::UNKOWN),
+        confidence_interval: (bias::UNKOWN, bias::UNKOWN)
+        };
+
+    /** Constructor, all values are unknown for an empty sample. */
+    pub fn new<I>(values: I) -> Self
+    where I: IntoIterator<Item = f64> {
+        let mut sorted: Vec<_> = values.into_iter().collect();
+        if sorted.is_empty() {
+            return Self::UNKNOWN;
+            }
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        /* Calculate the mean, and it's margin of error - none for a single value */
+        let mean = mean(&sorted);
+        let std_dev = std_dev(&sorted, mean);
+        let margin = match sorted.len() {
+            1 => 0.0,
+            len => student_quantile_95(len - 1) * std_dev / (len as f64).sqrt()
+            };
+
+        Self {
+            mean,
+            std_dev,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            median: quantile(&sorted, 0.5),
+            quartiles: (quantile(&sorted, 0.25), quantile(&sorted, 0.75)),
+            confidence_interval: (mean - margin, mean + margin)
+            }
+        }
+
+    /** `mean` getter. */
+    #[inline]
+    pub const fn mean(&self) -> f64
+        { self.mean }
+    /** `std_dev` getter. */
+    #[inline]
+    pub const fn std_dev(&self) -> f64
+        { self.std_dev }
+    /** `min` getter. */
+    #[inline]
+    pub const fn min(&self) -> f64
+        { self.min }
+    /** `max` getter. */
+    #[inline]
+    pub const fn max(&self) -> f64
+        { self.max }
+    /** `median` getter. */
+    #[inline]
+    pub const fn median(&self) -> f64
+        { self.median }
+    /** `quartiles` getter. */
+    #[inline]
+    pub const fn quartiles(&self) -> (f64, f64)
+        { self.quartiles }
+    /** `confidence_interval` getter. */
+    #[inline]
+    pub const fn confidence_interval(&self) -> (f64, f64)
+        { self.confidence_interval }
+    }
+
+/* **Technical part** - trait implementation for spread printing. */
+impl Display for Spread {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "mean: {}, std: {}, min: {}, max: {}, median: {}, quartiles: {} - {}, 95% ci: {} - {}",
+            self.mean, self.std_dev, self.min, self.max, self.median,
+            self.quartiles.0, self.quartiles.1,
+            self.confidence_interval.0, self.confidence_interval.1
+            )
+        }
+    }
+
 /** **Technical part** - structure for holding averaged statistics of a batch simulation. */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AveragedStats {
     /** Number of times simulation was run. */
     batch_size: usize,
@@ -452,6 +1474,32 @@ pub struct AveragedStats {
     avg_completed_routes: f64,
     /** Average amount of pheromones on each point per average routes. */
     avg_pheromone_per_route: Box<[f64]>,
+    /** Average amount of pheromones on each covered edge. */
+    avg_edge_pheromone_strengths: BTreeMap<String, f64>,
+    /** Shortest route found in the whole batch, in metric units. */
+    best_route: Option<BestRoute>,
+    /** Route with the fewest hops found in the whole batch. */
+    fewest_hops_route: Option<BestRoute>,
+    /** Average length of runs' shortest routes. */
+    avg_best_route_length: Option<f64>,
+    /** Average length of the shortest route finished in each cycle. */
+    avg_best_length_per_phase: Box<[Option<f64>]>,
+    /** Average of the average length of routes finished in each cycle. */
+    avg_average_length_per_phase: Box<[Option<f64>]>,
+    /** Spread of ants' average route length. */
+    route_len_spread: Spread,
+    /** Spread of the average number of routes per ant. */
+    completed_routes_spread: Spread,
+    /** Spread of the amount of pheromones on each point. */
+    pheromone_spreads: Box<[Spread]>,
+    /** Spread of the amount of satiated ants for each cycle. */
+    ants_per_phase_spreads: Box<[Spread]>,
+    /** Number of times each distinct route was finished by ants in the whole batch. */
+    route_histogram: BTreeMap<Route, usize>,
+    /** Number of runs stopped by each rule. */
+    stop_reasons: BTreeMap<StopReason, usize>,
+    /** Average number of cycles run. */
+    avg_cycles_run: f64
     }
 
 impl AveragedStats {
@@ -468,6 +1516,10 @@ impl AveragedStats {
         let mut total_pheromone_strengths = vec![0.0; number_of_points].into_boxed_slice();
         let mut total_ants_per_phase = vec![0; cycles].into_boxed_slice();
         let mut total_pheromone_per_route = vec![0.0; number_of_points].into_boxed_slice();
+        let mut total_edge_pheromone_strengths = BTreeMap::new();
+        let mut route_histogram = BTreeMap::new();
+        let mut stop_reasons = BTreeMap::new();
+        let mut total_cycles_run = 0;
 
         /* Get total statistics for whole batch */
         for stat in stats {
@@ -482,8 +1534,19 @@ impl AveragedStats {
                 *total_avg_strength += avg_strength;
                 }
 
-            for (total_ants, &ants) in zip(&mut total_ants_per_phase, &stat.ants_per_phase) {
-                *total_ants += ants;
+            /* Runs stopped early keep their last state for the remaining cycles */
+            for (phase, total_ants) in total_ants_per_phase.iter_mut().enumerate() {
+                *total_ants += satiated_in_phase(&stat.ants_per_phase, phase);
+                }
+            *stop_reasons.entry(stat.stop_reason).or_default() += 1;
+            total_cycles_run += stat.cycles_run();
+
+            for (label, &strength) in &stat.edge_pheromone_strengths {
+                *total_edge_pheromone_strengths.entry(label.clone()).or_default() += strength;
+                }
+
+            for (route, &count) in &stat.route_histogram {
+                *route_histogram.entry(route.clone()).or_default() += count;
                 }
             }
 
@@ -492,10 +1555,40 @@ impl AveragedStats {
             *strength /= batch;
             *avg_strength /= batch;
             }
+        for strength in total_edge_pheromone_strengths.values_mut() {
+            *strength /= batch;
+            }
         let avg_ants_per_phase = total_ants_per_phase.into_iter()
             .map(|ants| (ants as f64) / batch)
             .collect();
 
+        /* Find the batch's shortest route, and average the lengths over runs, which have finished any */
+        let best_route = stats.iter()
+            .filter_map(|stat| stat.best_route.as_ref())
+            .min_by(|a, b| a.length.total_cmp(&b.length))
+            .cloned();
+        let fewest_hops_route = stats.iter()
+            .filter_map(|stat| stat.fewest_hops_route.as_ref())
+            .min_by_key(|best| best.hops())
+            .cloned();
+        let avg_best_route_length = average_present(stats.iter().map(|stat| stat.best_route.as_ref().map(BestRoute::length)));
+        let avg_best_length_per_phase = (0 .. cycles)
+            .map(|phase| average_present(stats.iter().map(|stat| stat.best_length_per_phase.get(phase).copied().flatten())))
+            .collect();
+        let avg_average_length_per_phase = (0 .. cycles)
+            .map(|phase| average_present(stats.iter().map(|stat| stat.average_length_per_phase.get(phase).copied().flatten())))
+            .collect();
+
+        /* Get the spreads of the values */
+        let route_len_spread = Spread::new(stats.iter().map(|stat| stat.average_route_len));
+        let completed_routes_spread = Spread::new(stats.iter().map(|stat| stat.completed_routes));
+        let pheromone_spreads = (0 .. number_of_points)
+            .map(|index| Spread::new(stats.iter().map(|stat| stat.pheromone_strengths[index])))
+            .collect();
+        let ants_per_phase_spreads = (0 .. cycles)
+            .map(|phase| Spread::new(stats.iter().map(|stat| satiated_in_phase(&stat.ants_per_phase, phase) as f64)))
+            .collect();
+
         /* Create averaged stats */
         Self {
             batch_size,
@@ -504,28 +1597,252 @@ impl AveragedStats {
             avg_completed_routes: total_returns / batch,
             avg_pheromone_strengths: total_pheromone_strengths,
             avg_pheromone_per_route: total_pheromone_per_route,
-            avg_ants_per_phase
+            avg_edge_pheromone_strengths: total_edge_pheromone_strengths,
+            avg_ants_per_phase,
+            best_route,
+            fewest_hops_route,
+            avg_best_route_length,
+            avg_best_length_per_phase,
+            avg_average_length_per_phase,
+            route_len_spread,
+            completed_routes_spread,
+            pheromone_spreads,
+            ants_per_phase_spreads,
+            route_histogram,
+            stop_reasons,
+            avg_cycles_run: total_cycles_run as f64 / batch
             }
         }
 
+    /** `batch_size` getter. */
+    #[inline]
+    pub const fn batch_size(&self) -> usize
+        { self.batch_size }
+    /** `total_complete_routes` getter. */
+    #[inline]
+    pub const fn total_complete_routes(&self) -> usize
+        { self.total_complete_routes }
+    /** `avg_pheromone_strengths` getter. */
+    #[inline]
+    pub fn avg_pheromone_strengths(&self) -> &[f64]
+        { &self.avg_pheromone_strengths }
+    /** `avg_route_len` getter. */
+    #[inline]
+    pub const fn avg_route_len(&self) -> f64
+        { self.avg_route_len }
+    /** `avg_ants_per_phase` getter. */
+    #[inline]
+    pub fn avg_ants_per_phase(&self) -> &[f64]
+        { &self.avg_ants_per_phase }
+    /** `avg_completed_routes` getter. */
+    #[inline]
+    pub const fn avg_completed_routes(&self) -> f64
+        { self.avg_completed_routes }
+    /** `avg_pheromone_per_route` getter. */
+    #[inline]
+    pub fn avg_pheromone_per_route(&self) -> &[f64]
+        { &self.avg_pheromone_per_route }
+    /** `avg_edge_pheromone_strengths` getter. */
+    #[inline]
+    pub const fn avg_edge_pheromone_strengths(&self) -> &BTreeMap<String, f64>
+        { &self.avg_edge_pheromone_strengths }
+    /** `best_route` getter. */
+    #[inline]
+    pub const fn best_route(&self) -> Option<&BestRoute>
+        { self.best_route.as_ref() }
+    /** `fewest_hops_route` getter. */
+    #[inline]
+    pub const fn fewest_hops_route(&self) -> Option<&BestRoute>
+        { self.fewest_hops_route.as_ref() }
+    /** `avg_best_route_length` getter. */
+    #[inline]
+    pub const fn avg_best_route_length(&self) -> Option<f64>
+        { self.avg_best_route_length }
+    /** `avg_best_length_per_phase` getter. */
+    #[inline]
+    pub fn avg_best_length_per_phase(&self) -> &[Option<f64>]
+        { &self.avg_best_length_per_phase }
+    /** `avg_average_length_per_phase` getter. */
+    #[inline]
+    pub fn avg_average_length_per_phase(&self) -> &[Option<f64>]
+        { &self.avg_average_length_per_phase }
+
+    /** `route_len_spread` getter. */
+    #[inline]
+    pub const fn route_len_spread(&self) -> Spread
+        { self.route_len_spread }
+    /** `completed_routes_spread` getter. */
+    #[inline]
+    pub const fn completed_routes_spread(&self) -> Spread
+        { self.completed_routes_spread }
+    /** `pheromone_spreads` getter. */
+    #[inline]
+    pub fn pheromone_spreads(&self) -> &[Spread]
+        { &self.pheromone_spreads }
+    /** `ants_per_phase_spreads` getter. */
+    #[inline]
+    pub fn ants_per_phase_spreads(&self) -> &[Spread]
+        { &self.ants_per_phase_spreads }
+
+    /** `route_histogram` getter. */
+    #[inline]
+    pub const fn route_histogram(&self) -> &BTreeMap<Route, usize>
+        { &self.route_histogram }
+    /** `stop_reasons` getter. */
+    #[inline]
+    pub const fn stop_reasons(&self) -> &BTreeMap<StopReason, usize>
+        { &self.stop_reasons }
+    /** `avg_cycles_run` getter. */
+    #[inline]
+    pub const fn avg_cycles_run(&self) -> f64
+        { self.avg_cycles_run }
+
     /** Show operation for averages of a batch simulation. */
     pub fn show(&self) {
         println!(
 "o> ------ AVG STATS OF {:>3} ------ <o
+|              stopped by: {}
+|      average cycles run: {}
 |  total completed routes: {}
 |    pheromones per point: {}
 |    average route length: {}
 | satiated ants per phase: {}
 |  average routes per ant: {}
 |    pheromones per route: {}
-o> ------------------------------ <o",
+|        batch best route: {}
+| batch fewest hops route: {}
+|              top routes: {}
+|   avg best route length: {}
+|   best length per phase: {}
+|    avg length per phase: {}
+|     route length spread: {}
+|   routes per ant spread: {}
+{}{}{}o> ------------------------------ <o",
             self.batch_size,
+            show_stop_reasons(&self.stop_reasons),
+            self.avg_cycles_run,
             self.total_complete_routes,
             self.avg_pheromone_strengths.show_slice(),
             self.avg_route_len,
             self.avg_ants_per_phase.show_slice(),
             self.avg_completed_routes,
-            self.avg_pheromone_per_route.show_slice()
+            self.avg_pheromone_per_route.show_slice(),
+            self.best_route.show_or_none(),
+            self.fewest_hops_route.show_or_none(),
+            show_top_routes(&self.route_histogram),
+            self.avg_best_route_length.show_or_none(),
+            show_lengths(&self.avg_best_length_per_phase),
+            show_lengths(&self.avg_average_length_per_phase),
+            self.route_len_spread,
+            self.completed_routes_spread,
+            show_spreads("pheromones of point", &self.pheromone_spreads),
+            show_spreads("satiated in phase", &self.ants_per_phase_spreads),
+            show_edges(&self.avg_edge_pheromone_strengths)
             );
         }
-    }
\ No newline at end of file
+    }
+
+/** **Technical part** - helper function for printing a statistics' line of edges' pheromones, empty if there are none. */
+fn show_edges(edges: &BTreeMap<String, f64>) -> String {
+    if edges.is_empty() {
+        return String::new();
+        }
+
+    /* Label every edge's value */
+    let values = edges.iter()
+        .map(|(label, pheromone)| format!("{label}: {pheromone}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("|     pheromones per edge: {values}\n")
+    }
+
+/** **Technical part** - helper function for printing statistics' lines of spreads, labeled with their numbers. */
+fn show_spreads(label: &str, spreads: &[Spread]) -> String {
+    spreads.iter()
+        .zip(1 ..)
+        .fold(String::new(), |mut tmp, (spread, number)| {
+            _ = writeln!(tmp, "| {:>23}: {spread}", format!("{label} {number}"));
+            tmp
+            })
+    }
+
+/** **Technical part** - helper function for printing numbers of runs stopped by each rule. */
+fn show_stop_reasons(stop_reasons: &BTreeMap<StopReason, usize>) -> String {
+    stop_reasons.iter()
+        .map(|(reason, count)| format!("{reason}: {count}"))
+        .collect::<Vec<_>>()
+        .show_slice()
+    }
+
+/** **Technical part** - helper function for getting the number of satiated ants in a cycle, runs stopped early keep their last number. */
+fn satiated_in_phase(ants_per_phase: &[usize], phase: usize) -> usize {
+    ants_per_phase.get(phase)
+        .or(ants_per_phase.last())
+        .copied()
+        .unwrap_or_default()
+    }
+
+/** **Technical part** - helper function for printing the most frequent routes, with their share of all finished routes. */
+fn show_top_routes(histogram: &BTreeMap<Route, usize>) -> String {
+    let total: usize = histogram.values().sum();
+
+    /* Order routes from the most frequent, ties keep the routes' order */
+    let mut routes: Vec<_> = histogram.iter().collect();
+    routes.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    (total != 0)
+        .then(|| routes.iter()
+            .take(SHOWN_ROUTES)
+            .map(|&(route, &count)| format!("{route}: {:.1}% ({count})", 100.0 * count as f64 / total as f64))
+            .collect::<Vec<_>>()
+            .show_slice()
+            )
+        .show_or_none()
+    }
+
+/** **Technical part** - helper function for printing per-cycle lengths, with cycles without any finished route shown as none. */
+fn show_lengths(lengths: &[Option<f64>]) -> String {
+    lengths.iter()
+        .map(ShowOption::show_or_none)
+        .collect::<Vec<_>>()
+        .show_slice()
+    }
+
+/** **Technical part** - helper function for averaging the present values, none if there are none. */
+fn average_present<I>(values: I) -> Option<f64>
+where I: IntoIterator<Item = Option<f64>> {
+    let (count, total) = values.into_iter()
+        .flatten()
+        .fold((0, 0.0), |(count, total), value| (count + 1, total + value));
+
+    (count != 0).then(|| total / count as f64)
+    }
+
+/** **Technical part** - serialization of floats, which might be unknown, with the unknown value written as none. */
+mod unknown_float {
+    use {
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serialize,
+            Serializer
+            },
+        crate::consts::bias
+        };
+
+    /** Serialize the value, or none if it's unknown. */
+    pub fn serialize<S>(value: &f64, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        (! value.is_nan())
+            .then_some(*value)
+            .serialize(serializer)
+        }
+
+    /** Deserialize the value, with none being unknown. */
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    where D: Deserializer<'de> {
+        Option::deserialize(deserializer)
+            .map(|value| value.unwrap_or(bias::UNKOWN))
+        }
+    }
@@ -0,0 +1,179 @@
+/*! **Technical module** - automatic tuning of the simulation's configuration. */
+
+use {
+    serde_json::Value,
+    std::collections::BTreeMap,
+    crate::{
+        info,
+        consts::limits::BATCH_RANGE,
+        error::SweepError,
+        simul::Simulator,
+        sweep::{
+            configure,
+            show_parameters,
+            Parameter,
+            Sweep
+            },
+        tech::{
+            Config,
+            DisjointConfig,
+            Objective
+            }
+        }
+    };
+
+
+
+/** **Technical part** - structure for holding a round of the tuning - the candidates, which took part in it, from the best one. */
+#[derive(Debug, Clone)]
+pub struct TuningRound {
+    /** Number of runs per candidate. */
+    runs: usize,
+    /** Candidates' parameters' values, and their objective's values. */
+    candidates: Vec<(BTreeMap<String, Value>, f64)>
+    }
+
+impl TuningRound {
+    /** `runs` getter. */
+    #[inline]
+    pub const fn runs(&self) -> usize
+        { self.runs }
+    /** `candidates` getter. */
+    #[inline]
+    pub fn candidates(&self) -> &[(BTreeMap<String, Value>, f64)]
+        { &self.candidates }
+    }
+
+/** `Tuner` structure, for searching the parameters' values, which best meet the objective - with successive halving, each round drops the worse half of the candidates, and doubles the runs of the rest. */
+#[derive(Debug, Clone)]
+pub struct Tuner {
+    /** Base configuration, overridden by the parameters. */
+    config: Config,
+    /** Settings shared by all batches. */
+    disjoint_config: DisjointConfig,
+    /** Candidates' parameters' values. */
+    candidates: Vec<BTreeMap<String, Value>>,
+    /** Objective to meet. */
+    objective: Objective,
+    /** Possible cycle for the satiated ants' objective. */
+    cycle: Option<usize>,
+    /** Number of runs per candidate in the first round. */
+    runs: usize,
+    /** Finished rounds. */
+    rounds: Vec<TuningRound>
+    }
+
+impl Tuner {
+    /** Constructor, the candidates are all combinations of the parameters' values. */
+    pub fn new(mut config: Config, disjoint_config: DisjointConfig, parameters: Vec<Parameter>, objective: (Objective, Option<usize>), runs: usize) -> Result<Self, SweepError> {
+        let (objective, cycle) = objective;
+
+        /* If not set, draw the seed once, so all candidates are compared on the same runs' seeds */
+        config.seed.get_or_insert_with(|| fastrand::u64(..));
+
+        /* Reuse the sweep's checks, and combinations */
+        let sweep = Sweep::new(config.clone(), disjoint_config, parameters)?;
+        let candidates = sweep.combinations();
+
+        Ok(Self {
+            config,
+            disjoint_config: sweep.disjoint_config().clone(),
+            candidates,
+            objective,
+            cycle,
+            runs,
+            rounds: Vec::new()
+            })
+        }
+
+    /** Run the rounds, until a single candidate is left - the survivors keep their runs, and simulate only the new ones. */
+    pub fn simulate(&mut self) -> Result<(), SweepError> {
+        /* Configure all candidates first, so invalid values fail early */
+        let mut survivors = self.candidates.iter()
+            .map(|parameters| Ok((parameters.clone(), configure(&self.config, parameters)?, Vec::new())))
+            .collect::<Result<Vec<_>, SweepError>>()?;
+        let mut runs = self.runs.clamp(BATCH_RANGE.start as usize, BATCH_RANGE.end as usize - 1);
+
+        loop {
+            /* Write information */
+            info!("Round {}: {} candidates, {} runs each", self.rounds.len() + 1, survivors.len(), runs);
+
+            /* Run the missing part of the batch for every candidate */
+            let disjoint_config = DisjointConfig {
+                batch_size: runs,
+                ..self.disjoint_config.clone()
+                };
+            let mut scored = Vec::with_capacity(survivors.len());
+            for (parameters, config, mut stats) in survivors {
+                let mut simulator = Simulator::new(config.clone(), disjoint_config.clone())?;
+                simulator.skip_runs(stats.len());
+                simulator.simulate()?;
+                stats.extend_from_slice(simulator.stats());
+
+                let value = self.objective.value(&stats, self.cycle);
+                scored.push((parameters, config, stats, value));
+                }
+
+            /* Order from the best one, ties keep the candidates' order */
+            scored.sort_by(|(.., a), (.., b)| self.objective.compare(*a, *b));
+            self.rounds.push(TuningRound {
+                runs,
+                candidates: scored.iter()
+                    .map(|(parameters, .., value)| (parameters.clone(), *value))
+                    .collect()
+                });
+
+            /* Drop the worse half, two candidates are settled at once */
+            let keep = match scored.len() {
+                ..= 2 => 1,
+                count => count.div_ceil(2)
+                };
+            scored.truncate(keep);
+            if keep == 1 {
+                break;
+                }
+
+            survivors = scored.into_iter()
+                .map(|(parameters, config, stats, _)| (parameters, config, stats))
+                .collect();
+            runs = (runs * 2).min(BATCH_RANGE.end as usize - 1);
+            }
+
+        Ok(())
+        }
+
+    /** `objective` getter. */
+    #[inline]
+    pub const fn objective(&self) -> Objective
+        { self.objective }
+    /** `rounds` getter. */
+    #[inline]
+    pub fn rounds(&self) -> &[TuningRound]
+        { &self.rounds }
+    /** Possible winning candidate's parameters' values, and the objective's value. */
+    pub fn winner(&self) -> Option<&(BTreeMap<String, Value>, f64)> {
+        self.rounds.last()
+            .and_then(|round| round.candidates.first())
+        }
+    /** Possible winning configuration. */
+    pub fn winning_config(&self) -> Option<Config> {
+        self.winner()
+            .and_then(|(parameters, _)| configure(&self.config, parameters).ok())
+        }
+
+    /** Show the rounds, and the winner. */
+    pub fn show(&self) {
+        println!("o> ------------- TUNE ------------- <o");
+        println!("|   objective: {}", self.objective);
+        for (number, TuningRound { runs, candidates }) in self.rounds.iter().enumerate() {
+            println!("| round {}, {} runs per candidate", number + 1, runs);
+            for (parameters, value) in candidates {
+                println!("|   {}: {}", show_parameters(parameters), value);
+                }
+            }
+        if let Some((parameters, value)) = self.winner() {
+            println!("|      winner: {}: {}", show_parameters(parameters), value);
+            }
+        println!("o> -------------------------------- <o");
+        }
+    }
@@ -1,6 +1,10 @@
 /*! **Logic module** - grouping of other important structures. */
 
 use {
+    serde::{
+        Deserialize,
+        Serialize
+        },
     core::str::FromStr,
     crate::{
         consts::limits::GRID_RANGE,
@@ -15,14 +19,14 @@ use {
 
 
 /** `Ant` structure, basic logical unit. */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Ant {
     /** Check, whether the ant found food. */
     pub satiated: bool,
     /** Ant's current route. */
     pub route: Route,
     /** Number of routes the ant went through. */
-    pub routes_counter: u8
+    pub routes_counter: usize
     }
 
 impl Ant {
@@ -54,7 +58,7 @@ impl Ant {
     }
 
 /** `Point` structure, for holding basic point data. */
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Point {
     /** Unique point ID. */
     pub id: Id,
@@ -63,8 +67,10 @@ pub struct Point {
     /** Point's y coordinate. */
     pub y: i16,
     /** Point's current amount of pheromones. */
+    #[serde(skip)]
     pub pheromone: f64,
     /** Point's current amount of food. */
+    #[serde(default, rename = "food")]
     pub food_amount: u32
     }
 
@@ -178,51 +184,77 @@ pub mod distance {
 
 /** Functions for calculating pheromone dispersion. */
 pub mod disperse {
-    use crate::utils::Point;
-
     /** Linear dispersion calculation. */
     #[inline]
-    pub const fn linear(point: &Point, factor: f64) -> f64
-        { point.pheromone - factor }
+    pub const fn linear(pheromone: f64, factor: f64) -> f64
+        { pheromone - factor }
     /** Exponential dispersion calculation. */
     #[inline]
-    pub const fn exponential(point: &Point, factor: f64) -> f64
-        { point.pheromone / factor }
+    pub const fn exponential(pheromone: f64, factor: f64) -> f64
+        { pheromone / factor }
     /** Relative dispersion calculation. */
     #[inline]
-    pub const fn relative(point: &Point, factor: f64) -> f64
-        { point.pheromone * (1.0 - factor) }
+    pub const fn relative(pheromone: f64, factor: f64) -> f64
+        { pheromone * (1.0 - factor) }
     }
 
-/** Functions for calculating point preference. */
+/** Functions for calculating amount of deposited pheromones. */
+pub mod deposit {
+    /** Constant deposit calculation. */
+    #[inline]
+    pub const fn constant(pheromone: f64) -> f64
+        { pheromone }
+    /** Deposit calculation inversely proportional to the route's length. */
+    #[inline]
+    pub const fn inverse(pheromone: f64, length: f64) -> f64
+        { pheromone / length }
+    }
+
+/** Functions for calculating point preference, the pheromone value is the one leading to the point, each factor is raised to it's exponent. */
 pub mod preference {
     use crate::{
         consts::bias,
-        tech::Metric,
+        tech::{
+            Exponents,
+            Metric
+            },
         utils::Point
         };
 
+    /** **Technical part** - helper function for calculating the distance factor's denominator. */
+    #[inline]
+    fn dist(point: &Point, x: i16, y: i16, metric: Metric, exponents: Exponents) -> f64
+        { metric.calculate(x, y, point.x, point.y).powf(exponents.beta) }
+    /** **Technical part** - helper function for calculating the pheromone factor. */
+    #[inline]
+    fn phero(pheromone: f64, exponents: Exponents) -> f64
+        { (pheromone + bias::NEUTRAL).powf(exponents.alpha) }
+    /** **Technical part** - helper function for calculating the food factor. */
+    #[inline]
+    fn fd(point: &Point, exponents: Exponents) -> f64
+        { (point.food_amount as f64 + bias::NEUTRAL).powf(exponents.gamma) }
+
     /** Point prefrence calculation for distance. */
-    pub fn distance(point: &Point, x: i16, y: i16, metric: Metric) -> f64
-        { bias::NEUTRAL / metric.calculate(x, y, point.x, point.y) }
+    pub fn distance(point: &Point, x: i16, y: i16, metric: Metric, exponents: Exponents) -> f64
+        { bias::NEUTRAL / dist(point, x, y, metric, exponents) }
     /** Point prefrence calculation for pheromones. */
-    pub const fn pheromone(point: &Point) -> f64
-        { point.pheromone + bias::NEUTRAL }
+    pub fn pheromone(pheromone: f64, exponents: Exponents) -> f64
+        { phero(pheromone, exponents) }
     /** Point prefrence calculation for food. */
-    pub const fn food(point: &Point) -> f64
-        { point.food_amount as f64 + bias::NEUTRAL }
+    pub fn food(point: &Point, exponents: Exponents) -> f64
+        { fd(point, exponents) }
     /** Point prefrence calculation for pheromones, and distance. */
-    pub fn phero_dist(point: &Point, x: i16, y: i16, metric: Metric) -> f64
-        { (point.pheromone + bias::NEUTRAL) / metric.calculate(x, y, point.x, point.y) }
+    pub fn phero_dist(point: &Point, pheromone: f64, x: i16, y: i16, metric: Metric, exponents: Exponents) -> f64
+        { phero(pheromone, exponents) / dist(point, x, y, metric, exponents) }
     /** Point prefrence calculation for food, and distance. */
-    pub fn food_dist(point: &Point, x: i16, y: i16, metric: Metric) -> f64
-        { (point.food_amount as f64 + bias::NEUTRAL) / metric.calculate(x, y, point.x, point.y) }
+    pub fn food_dist(point: &Point, x: i16, y: i16, metric: Metric, exponents: Exponents) -> f64
+        { fd(point, exponents) / dist(point, x, y, metric, exponents) }
     /** Point prefrence calculation for pheromones, and food. */
-    pub const fn phero_food(point: &Point) -> f64
-        { (point.pheromone + bias::NEUTRAL) * (point.food_amount as f64 + bias::NEUTRAL) }
+    pub fn phero_food(point: &Point, pheromone: f64, exponents: Exponents) -> f64
+        { phero(pheromone, exponents) * fd(point, exponents) }
     /** Point prefrence calculation for pheromones, food, and distance. */
-    pub fn phero_food_dist(point: &Point, x: i16, y: i16, metric: Metric) -> f64
-        { (point.pheromone + bias::NEUTRAL) * (point.food_amount as f64 + bias::NEUTRAL) / metric.calculate(x, y, point.x, point.y) }
+    pub fn phero_food_dist(point: &Point, pheromone: f64, x: i16, y: i16, metric: Metric, exponents: Exponents) -> f64
+        { phero(pheromone, exponents) * fd(point, exponents) / dist(point, x, y, metric, exponents) }
     }
 
 /** Functions for calculating new indices. */
@@ -243,12 +275,20 @@ pub mod selection {
     pub fn randomly(decision_points: usize) -> usize {
         random_usize(.. decision_points)
         }
-    /** Roulette selection method. */
+    /** Roulette selection method, the auxils have to be sorted from the biggest ratio - an infinite wheel falls back to the greedy selection, and an empty, or undefined one to the random selection. */
     pub fn roulette(decision_points: usize, axuils: &[Auxil]) -> usize {
         /* Sum the wheel */
         let sum: f64 = axuils.iter()
             .map(|auxil| auxil.ratio)
             .sum();
+
+        /* Fallbacks for the wheels, which can't be spun */
+        if sum.is_infinite() {
+            return greedy();
+            }
+        if sum.is_nan() || sum <= 0.0 {
+            return randomly(decision_points);
+            }
         
         /* Select random chance, and scale it with the sum */
         let chance = random_f64() * sum;
@@ -265,4 +305,165 @@ pub mod selection {
         /* Fallback for rare float precision errors */
         decision_points.saturating_sub(1)
         }
-    }
\ No newline at end of file
+    /** Pseudo-random proportional selection method - greedy with the `q0` probability, roulette otherwise. */
+    pub fn proportional(decision_points: usize, axuils: &[Auxil], q0: f64) -> usize {
+        if random_f64() < q0 {
+            greedy()
+        } else {
+            roulette(decision_points, axuils)
+            }
+        }
+    }
+
+/** Functions for calculating statistics of a sample. */
+pub mod statistics {
+    use {
+        sqds_tools::select,
+        core::f64::consts::SQRT_2,
+        crate::consts::bias::{
+            CONFIDENCE_95,
+            STUDENT_95
+            }
+        };
+
+    /** Arithmetic mean calculation, the sample can't be empty. */
+    pub fn mean(values: &[f64]) -> f64 {
+        values.iter().sum::<f64>() / values.len() as f64
+        }
+    /** Sample standard deviation calculation, zero for samples smaller than 2. */
+    pub fn std_dev(values: &[f64], mean: f64) -> f64 {
+        if values.len() < 2 {
+            return 0.0;
+            }
+
+        let squares: f64 = values.iter()
+            .map(|value| (value - mean).powi(2))
+            .sum();
+
+        (squares / (values.len() - 1) as f64).sqrt()
+        }
+    /** Quantile calculation with linear interpolation, the sample has to be sorted, and can't be empty - equal neighbours, even infinite, are returned as they are. */
+    pub fn quantile(sorted: &[f64], q: f64) -> f64 {
+        let position = q * (sorted.len() - 1) as f64;
+        let index = position.floor() as usize;
+        let (lower, upper) = (sorted[index], sorted[position.ceil() as usize]);
+
+        select!(lower == upper, lower, lower + (upper - lower) * (position - index as f64))
+        }
+    /** Student's t quantile calculation for the 95% confidence interval - tabled up to 30 degrees of freedom, and the Cornish-Fisher expansion above, with error below `1e-7`. */
+    pub fn student_quantile_95(degrees: usize) -> f64 {
+        if let Some(&quantile) = degrees.checked_sub(1).and_then(|index| STUDENT_95.get(index)) {
+            return quantile;
+            }
+
+        let (z, n) = (CONFIDENCE_95, degrees as f64);
+        let g1 = (z.powi(3) + z) / 4.0;
+        let g2 = (5.0 * z.powi(5) + 16.0 * z.powi(3) + 3.0 * z) / 96.0;
+        let g3 = (3.0 * z.powi(7) + 19.0 * z.powi(5) + 17.0 * z.powi(3) - 15.0 * z) / 384.0;
+        let g4 = (79.0 * z.powi(9) + 776.0 * z.powi(7) + 1482.0 * z.powi(5) - 1920.0 * z.powi(3) - 945.0 * z) / 92160.0;
+
+        z + g1 / n + g2 / n.powi(2) + g3 / n.powi(3) + g4 / n.powi(4)
+        }
+    /** Ranks calculation, counted from 1, tied values get their average rank - also returns the sum of `t^3 - t` over the ties' sizes. */
+    pub fn ranks(values: &[f64]) -> (Vec<f64>, f64) {
+        let mut order: Vec<_> = (0 .. values.len()).collect();
+        order.sort_unstable_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+        let mut ranks = vec![0.0; values.len()];
+        let mut ties = 0.0;
+        let mut start = 0;
+        while start < order.len() {
+            /* Find the end of the tied group */
+            let mut end = start + 1;
+            while end < order.len() && values[order[end]] == values[order[start]] {
+                end += 1;
+                }
+
+            /* Give the group it's average rank */
+            let rank = (start + end + 1) as f64 / 2.0;
+            for &index in &order[start .. end] {
+                ranks[index] = rank;
+                }
+            let size = (end - start) as f64;
+            ties += size.powi(3) - size;
+            start = end;
+            }
+
+        (ranks, ties)
+        }
+    /** Complementary error function calculation, with fractional error below `1.2e-7`. */
+    pub fn erfc(x: f64) -> f64 {
+        let z = x.abs();
+        let t = 1.0 / (1.0 + 0.5 * z);
+
+        /* Chebyshev fitting */
+        let poly = -z * z - 1.26551223 + t * (1.00002368 + t * (0.37409196 + t * (0.09678418
+            + t * (-0.18628806 + t * (0.27886807 + t * (-1.13520398 + t * (1.48851587
+            + t * (-0.82215223 + t * 0.17087277))))))));
+        let value = t * poly.exp();
+
+        select!(x < 0.0, 2.0 - value, value)
+        }
+    /** Two-sided p-value calculation for the standard normal statistic. */
+    pub fn two_sided_p_value(z: f64) -> f64 {
+        erfc(z.abs() / SQRT_2).min(1.0)
+        }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /* Compares the floats up to the tolerance */
+        fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+            assert!((actual - expected).abs() <= tolerance, "{actual} isn't close to {expected}");
+            }
+
+        #[test]
+        fn ranks_with_ties() {
+            let (ranks, ties) = ranks(&[3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0]);
+
+            assert_eq!(ranks, [4.5, 1.5, 6.0, 1.5, 8.0, 11.0, 3.0, 10.0, 8.0, 4.5, 8.0]);
+            /* Two pairs, and a triple */
+            assert_close(ties, 6.0 + 6.0 + 24.0, 0.0);
+            }
+
+        #[test]
+        fn ranks_without_ties() {
+            let (ranks, ties) = ranks(&[0.3, 0.1, 0.2]);
+
+            assert_eq!(ranks, [3.0, 1.0, 2.0]);
+            assert_close(ties, 0.0, 0.0);
+            }
+
+        #[test]
+        fn erfc_known_values() {
+            assert_close(erfc(0.0), 1.0, 1e-7);
+            assert_close(erfc(0.5), 0.4795001221869535, 1e-7);
+            assert_close(erfc(-1.0), 1.842700792949715, 1e-7);
+            }
+
+        #[test]
+        fn two_sided_p_values() {
+            assert_close(two_sided_p_value(0.0), 1.0, 1e-7);
+            assert_close(two_sided_p_value(1.96), 0.04999579029644087, 1e-7);
+            assert_close(two_sided_p_value(-1.96), 0.04999579029644087, 1e-7);
+            }
+
+        #[test]
+        fn quantiles() {
+            let sorted = [1.0, 2.0, 3.0, 4.0];
+
+            assert_close(quantile(&sorted, 0.5), 2.5, 1e-12);
+            assert_close(quantile(&sorted, 0.25), 1.75, 1e-12);
+            assert_eq!(quantile(&[1.0, f64::INFINITY, f64::INFINITY], 0.5), f64::INFINITY);
+            }
+
+        #[test]
+        fn student_quantiles() {
+            assert_close(student_quantile_95(1), 12.706204736174704, 1e-12);
+            assert_close(student_quantile_95(30), 2.042272456301238, 1e-12);
+            assert_close(student_quantile_95(31), 2.039513446396408, 1e-6);
+            assert_close(student_quantile_95(100), 1.983971518523552, 1e-6);
+            }
+        }
+    }
@@ -3,9 +3,14 @@
 use {
     arrayvec::ArrayVec,
     rustc_hash::FxBuildHasher,
+    serde::{
+        Deserialize,
+        Serialize
+        },
     sqds_tools::select,
     std::{
         collections::{
+            BTreeMap,
             HashSet,
             HashMap
             },
@@ -18,10 +23,7 @@ use {
             }
         },
     crate::{
-        consts::{
-            bias,
-            limits::MAX_POINTS
-            },
+        consts::limits::MAX_POINTS,
         error::NoFoodSourceError,
         tech::*,
         utils::*
@@ -30,6 +32,21 @@ use {
 
 
 
+/** **Technical part** - structure for holding the changing part of the world, for saving, and restoring it. */
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorldState {
+    /** Pheromones on the points in declaration order. */
+    pheromones: Box<[f64]>,
+    /** Food on the points in declaration order. */
+    food_amounts: Box<[u32]>,
+    /** Current points holding any food. */
+    food_source_ids: HashSet<Id, FxBuildHasher>,
+    /** Pheromones on the edges - empty for the point model. */
+    edges: Box<[f64]>,
+    /** Whether the edges were covered by the ants - empty for the point model. */
+    covered_edges: Box<[bool]>
+    }
+
 /** `World` structure, for handling most of logic operations, and managing the grid. */
 #[derive(Debug, Clone)]
 pub struct World {
@@ -45,14 +62,36 @@ pub struct World {
     initial_food_sources: HashMap<Id, u32, FxBuildHasher>,
     /** Number of decision points. */
     number_of_decision_points: usize,
+    /** Problem solved by the ants. */
+    problem: Problem,
+    /** Possible target point, the only goal of the ants. */
+    target: Option<Id>,
     /** Method of acquiring new index. */
     selection_method: Selection,
     /** Method of calculating point prefrence. */
     preference_method: Preference,
     /** Method of calculating distance. */
     distance_method: Metric,
+    /** Exponents of the point preference's factors. */
+    exponents: Exponents,
     /** Possible method of calculating dispersion, with it's coefficient. */
-    dispersion_method: Option<(Dispersion, f64)>
+    dispersion_method: Option<(Dispersion, f64)>,
+    /** Probability of the greedy choice, for the proportional selection. */
+    greediness: f64,
+    /** Variant of the algorithm. */
+    algorithm: Algorithm,
+    /** Local pheromone update coefficient, for the ACS. */
+    local_update_coefficient: f64,
+    /** Amount of pheromones the trails start with. */
+    initial_pheromone: f64,
+    /** Lower, and upper limit of pheromones on a trail. */
+    trail_limits: (f64, f64),
+    /** Places where pheromones are laid out. */
+    pheromone_model: PheromoneModel,
+    /** Pheromones on the edges, as a row-major matrix of source, and destination point indices - empty for the point model. */
+    edges: Box<[f64]>,
+    /** Whether the edges were covered by the ants during the run, in the same order - empty for the point model. */
+    covered_edges: Box<[bool]>
     }
 
 impl World {
@@ -65,15 +104,39 @@ impl World {
                 )
             .unzip();
 
+        /* Get the initial amount of pheromones, according to the algorithm */
+        let initial_pheromone = match config.algorithm {
+            Algorithm::Acs => config.tau0,
+            Algorithm::Mmas => config.tau_max,
+            _ => 0.0
+            };
+
+        /* Get the trail limits, according to the algorithm */
+        let trail_limits = match config.algorithm {
+            Algorithm::Mmas => (config.tau_min, config.tau_max),
+            _ => (0.0, f64::INFINITY)
+            };
+
         /* Convert the points list, and get length. */
-        let points = ArrayVec::from_iter(point_list);
+        let mut points = ArrayVec::from_iter(point_list);
         let num_of_points = points.len();
+        for point in &mut points {
+            point.pheromone = initial_pheromone;
+            }
 
         /* Crate auxils list. */
         let auxils = repeat_with(Auxil::default)
             .take(num_of_points)
             .collect();
 
+        /* Create edges matrix, if applicable */
+        let edges = match config.pheromone_model {
+            PheromoneModel::Point => Box::default(),
+            PheromoneModel::Edge => vec![initial_pheromone; num_of_points * num_of_points].into_boxed_slice()
+            };
+
+        let covered_edges = vec![false; edges.len()].into_boxed_slice();
+
         /* Crate world */
         Self {
             num_of_points,
@@ -82,10 +145,21 @@ impl World {
             food_source_ids,
             initial_food_sources,
             number_of_decision_points: config.decision,
+            problem: config.problem,
+            target: config.target,
             selection_method: config.select,
             preference_method: config.preference,
             distance_method: config.metric,
-            dispersion_method: config.dispersion.map(|dispersion| (dispersion, config.factor))
+            exponents: config.exponents(),
+            dispersion_method: config.dispersion.map(|dispersion| (dispersion, config.factor)),
+            greediness: config.q0,
+            algorithm: config.algorithm,
+            local_update_coefficient: config.xi,
+            initial_pheromone,
+            trail_limits,
+            pheromone_model: config.pheromone_model,
+            edges,
+            covered_edges
             }
         }
 
@@ -103,29 +177,46 @@ impl World {
             );
         }
 
-    /** Calculate new preference values for the points. */
-    fn calculate_preference(&mut self, visited: &Route) {
-        /* Get current postion's ID, and coordinates */
-        let (current_id, current_x, current_y) = {
+    /** Calculate new preference values for the points, and return the number of viable ones. */
+    fn calculate_preference(&mut self, visited: &Route) -> usize {
+        /* Get current postion's ID, index, and coordinates */
+        let (current_id, current_index, current_x, current_y) = {
             let id = visited.last()
                 .expect("Route should never be empty");
             
             /* Retrive point data */
-            let point = self.find_point(id);
-            (point.id, point.x, point.y)
+            let index = self.find_index(id);
+            let point = &self.points[index];
+            (point.id, index, point.x, point.y)
             };
 
-        /* Calculate preference scores for all the points, visited points get smallest score to avoid getting stuck */
-        for (auxil, point) in zip(&mut self.auxils, &self.points) {
-            let viable = ! visited.contains(&auxil.id) ||
-                self.food_source_ids.contains(&current_id);
-
-            /* If valiable, assign new ratio, otherwise, assign lowest value */
-            auxil.ratio = select!(viable,
-                self.preference_method.calculate(point, current_x, current_y, self.distance_method),
-                bias::MINUTE
-                )
+        /* Get pheromones leading from the current position, according to the model */
+        let trails = match self.pheromone_model {
+            PheromoneModel::Point => None,
+            PheromoneModel::Edge => self.edges.chunks_exact(self.num_of_points).nth(current_index)
             };
+
+        /* Check whether visited points are viable, tours, and paths to the target never allow it */
+        let revisits = self.is_foraging() &&
+            self.food_source_ids.contains(&current_id);
+
+        /* Calculate preference scores for all the points, visited points are sorted behind all viable ones, and left out of the choice */
+        let mut viable_points = 0;
+        for (index, (auxil, point)) in zip(&mut self.auxils, &self.points).enumerate() {
+            let viable = ! visited.contains(&auxil.id) || revisits;
+            let pheromone = trails.map_or(point.pheromone, |row| row[index]);
+
+            /* If valiable, assign new ratio - undefined ones are the lowest - otherwise, assign lower value than any */
+            auxil.ratio = if viable {
+                viable_points += 1;
+                let ratio = self.preference_method.calculate(point, pheromone, current_x, current_y, self.distance_method, self.exponents);
+                select!(ratio.is_nan(), 0.0, ratio)
+            } else {
+                f64::NEG_INFINITY
+                };
+            }
+
+        viable_points
         }
 
     /** Create new position according to passed arguments. */
@@ -133,61 +224,150 @@ impl World {
         /* Clear the helper array */
         self.reset_auxils();
         
-        /* Safety check - stop the simulation if true */
-        if self.food_source_ids.is_empty() {
+        /* Safety check - stop the simulation if true, tours, and paths to the target don't need food */
+        if self.is_foraging() && self.food_source_ids.is_empty() {
             return Err(NoFoodSourceError);
             }
 
-        /* Preference calculation */
-        self.calculate_preference(visited);
+        /* Close the tour, if all the points were visited */
+        if self.problem == Problem::Tour && visited.hops() + 1 >= self.num_of_points {
+            return Ok(visited.first().expect("Route should never be empty"));
+            }
+
+        /* Preference calculation, without viable points no food source can be reached */
+        let viable_points = self.calculate_preference(visited);
+        if viable_points == 0 {
+            return Err(NoFoodSourceError);
+            }
 
         /* Sort the helper array */
         self.sort_auxils();
 
-        /* Select only auxils from the range */
-        let auxils = self.auxils.get(.. self.number_of_decision_points)
+        /* Select only viable auxils from the range */
+        let decision_points = self.number_of_decision_points.min(viable_points);
+        let auxils = self.auxils.get(.. decision_points)
             .expect("Number of decision points should always be within range of auxils");
 
-        /* New position */
-        let choice = loop {
-            /* Get new position index */
-            let index = self.selection_method.calculate(self.number_of_decision_points, &auxils);
-
-            /* Get the element at the position */
-            let auxil = self.auxils.get(index)
-                .expect("Returned index should always be within range of auxils");
+        /* Get new position index */
+        let index = self.selection_method.calculate(decision_points, auxils, self.greediness);
 
-            /* Get new guesses, until unvisited is found */
-            if ! visited.contains(&auxil.id) {
-                break auxil;
-                }
-            };
+        /* Get the element at the position */
+        let choice = auxils.get(index)
+            .expect("Returned index should always be within range of auxils");
         
         /* Return ID of new position */
         Ok(choice.id)
         }
 
+    /** Apply the update to the pheromone trail leading from one point to another. */
+    fn update_trail<F>(&mut self, from_id: Id, to_id: Id, update: F)
+    where F: FnOnce(&mut f64) {
+        match self.pheromone_model {
+            PheromoneModel::Point => update(&mut self.find_point(to_id).pheromone),
+            PheromoneModel::Edge => {
+                let index = self.edge_index(from_id, to_id);
+                self.covered_edges[index] = true;
+                update(&mut self.edges[index])
+                }
+            }
+        }
+
+    /** Apply the update to the pheromone trails along the route, for the edge model the excluded IDs are the destinations. */
+    fn update_route<F>(&mut self, visited: &Route, exclude: &[Id], mut update: F)
+    where F: FnMut(&mut f64) {
+        match self.pheromone_model {
+            PheromoneModel::Point => {
+                let iter = self.points.iter_mut()
+                    .filter(|point|
+                        visited.contains(&point.id) &&
+                        ! exclude.contains(&point.id)
+                        );
+                for point in iter {
+                    update(&mut point.pheromone)
+                    }
+                },
+            PheromoneModel::Edge => {
+                let iter = visited.edges()
+                    .filter(|(_, to)| ! exclude.contains(to));
+                for (from, to) in iter {
+                    let index = self.edge_index(from, to);
+                    self.covered_edges[index] = true;
+                    update(&mut self.edges[index])
+                    }
+                }
+            }
+        }
+
     /** Cover the route with pheromones. */
     pub fn cover_route(&mut self, visited: &Route, exclude: &[Id], pheromone: f64) {
-        let iter = self.points.iter_mut()
-            .filter(|point|
-                visited.contains(&point.id) &&
-                ! exclude.contains(&point.id)
+        self.update_route(visited, exclude, |trail| *trail += pheromone);
+        }
+
+    /** Evaporate, and cover pheromones only along the route, if applicable - the ACS's global update. */
+    pub fn reinforce_route(&mut self, visited: &Route, exclude: &[Id], pheromone: f64) {
+        if let Some((dispersion, factor)) = self.dispersion_method {
+            self.update_route(visited, exclude, |trail|
+                *trail = dispersion.calculate(*trail, factor).max(0.0) + factor * pheromone
                 );
-        for point in iter {
-            point.pheromone += pheromone
             }
         }
 
-    /** Reduce amount of pheromones according to the function, if applicable. */
+    /** Move the pheromones of the route's last trail towards the initial amount, if applicable - the ACS's local update. */
+    pub fn local_update(&mut self, visited: &Route) {
+        if self.algorithm != Algorithm::Acs {
+            return;
+            }
+
+        /* Get the last move */
+        let Some((from, to)) = visited.edges().last() else {
+            return;
+            };
+
+        let (coefficient, initial) = (self.local_update_coefficient, self.initial_pheromone);
+        self.update_trail(from, to, |trail|
+            *trail = (1.0 - coefficient) * *trail + coefficient * initial
+            );
+        }
+
+    /** Reduce amount of pheromones according to the function, if applicable, and keep them within the trail limits - the ACS evaporates only during the global update. */
     pub fn disperse_pheromons(&mut self) {
-        if let Some((dispersion, factor)) = self.dispersion_method {
-            for point in &mut self.points {
-                point.pheromone = dispersion.calculate(point, factor).max(0.0)
+        if self.algorithm == Algorithm::Acs {
+            return;
+            }
+
+        let (min, max) = self.trail_limits;
+        let trails = self.points.iter_mut()
+            .map(|point| &mut point.pheromone)
+            .chain(&mut self.edges);
+        for pheromone in trails {
+            if let Some((dispersion, factor)) = self.dispersion_method {
+                *pheromone = dispersion.calculate(*pheromone, factor);
                 }
+            *pheromone = pheromone.clamp(min, max)
+            }
+        }
+
+    /** Reset pheromones on all trails to the initial amount. */
+    pub fn reset_trails(&mut self) {
+        self.edges.fill(self.initial_pheromone);
+        self.covered_edges.fill(false);
+        for point in &mut self.points {
+            point.pheromone = self.initial_pheromone;
             }
         }
 
+    /** Find the index of the point with given ID. */
+    fn find_index(&self, position_id: Id) -> usize {
+        self.points.iter()
+            .position(|point| point.id == position_id)
+            .expect("Passed ID should always belong to some existing point")
+        }
+
+    /** Find the index of the edge between points with given IDs. */
+    fn edge_index(&self, from_id: Id, to_id: Id) -> usize {
+        self.find_index(from_id) * self.num_of_points + self.find_index(to_id)
+        }
+
     /** Find the point with given ID. */
     fn find_point(&mut self, position_id: Id) -> &mut Point {
         self.points.iter_mut()
@@ -220,15 +400,30 @@ impl World {
         self.food_source_ids.contains(position_id)
         }
 
+    /** Check whether ants are looking for food - the foraging problem, without a target. */
+    pub fn is_foraging(&self) -> bool {
+        self.problem == Problem::Forage && self.target.is_none()
+        }
+
+    /** Check if the point is the goal of the ants - a food source, the target, or the anthill closing the tour. */
+    pub fn is_goal(&self, position_id: &Id, anthill_id: &Id) -> bool {
+        match (self.problem, self.target) {
+            (Problem::Tour, _) => position_id == anthill_id,
+            (Problem::Forage, Some(target)) => *position_id == target,
+            (Problem::Forage, None) => self.is_food_source(position_id)
+            }
+        }
+
     /** Reset points to original state - food, and pheromones. */
     pub fn reset(&mut self) {
         /* Clear available food sources */
         self.food_source_ids.clear();
 
+        /* Reset trails */
+        self.reset_trails();
+
         /* Reset points */
         for point in &mut self.points {
-            point.pheromone = 0.0;
-
             /* Additional reset if point had food initally */
             if let Some(&initial_value) = self.initial_food_sources.get(&point.id) {
                 point.food_amount = initial_value;
@@ -237,6 +432,33 @@ impl World {
             }
         }
 
+    /** Take the changing part of the world. */
+    pub fn state(&self) -> WorldState {
+        WorldState {
+            pheromones: self.points.iter()
+                .map(|point| point.pheromone)
+                .collect(),
+            food_amounts: self.food_per_point(),
+            food_source_ids: self.food_source_ids.clone(),
+            edges: self.edges.clone(),
+            covered_edges: self.covered_edges.clone()
+            }
+        }
+
+    /** Restore the changing part of the world, taken from the world of the same grid. */
+    pub fn restore(&mut self, state: WorldState) {
+        let WorldState { pheromones, food_amounts, food_source_ids, edges, covered_edges } = state;
+
+        for (point, (pheromone, food_amount)) in zip(&mut self.points, zip(pheromones, food_amounts)) {
+            point.pheromone = pheromone;
+            point.food_amount = food_amount;
+            }
+        self.food_source_ids = food_source_ids;
+
+        self.edges = edges;
+        self.covered_edges = covered_edges;
+        }
+
     /** Show a table of states of all points. */
     pub fn show(&self) {
         /* Preallocate string, 47 bytes is a rough estimate of format string length */
@@ -247,6 +469,11 @@ impl World {
             _ = writeln!(tmp, "| # {id}: {food:>4} - {pheromone}");
             }
 
+        /* Fill the string with covered edges, if applicable */
+        for (label, pheromone) in self.pheromones_per_edge() {
+            _ = writeln!(tmp, "| # {label}: {pheromone}");
+            }
+
         /* Print the table */
         println!("| o>--- world ---<o");
         print!("{tmp}");
@@ -269,14 +496,176 @@ impl World {
         println!("o> -------------- <o");
         }
 
+    /** Calculate the route's length in metric units. */
+    pub fn route_length(&self, route: &Route) -> f64 {
+        route.edges()
+            .map(|(from, to)| {
+                let (from, to) = (&self.points[self.find_index(from)], &self.points[self.find_index(to)]);
+                self.distance_method.calculate(from.x, from.y, to.x, to.y)
+                })
+            .sum()
+        }
+
+    /** `exponents` getter. */
+    #[inline]
+    pub const fn exponents(&self) -> Exponents
+        { self.exponents }
     /** `points`' length getter. */
     #[inline]
     pub const fn number_of_points(&self) -> usize
         { self.num_of_points }
-    /** `pheromones_per_point` getter. */
+    /** `pheromones_per_point` getter, for the edge model it's the sum of edges leading to the point from the other ones. */
     pub fn pheromones_per_point(&self) -> Box<[f64]> {
+        match self.pheromone_model {
+            PheromoneModel::Point =>
+                self.points.iter()
+                    .map(|point| point.pheromone)
+                    .collect(),
+            PheromoneModel::Edge =>
+                (0 .. self.num_of_points)
+                    .map(|to| self.edges.iter()
+                        .skip(to)
+                        .step_by(self.num_of_points)
+                        .enumerate()
+                        .filter(|&(from, _)| from != to)
+                        .map(|(_, pheromone)| pheromone)
+                        .sum()
+                        )
+                    .collect()
+            }
+        }
+    /** Shannon entropy of the pheromones' spread over the usable trails - points other than the anthill, or edges between distinct points - relative to the uniform spread over them, none if there are no pheromones. */
+    pub fn pheromone_entropy(&self) -> Option<f64> {
+        /* The anthill is the first point, and the diagonal edges lead nowhere */
+        let strengths: Vec<_> = match self.pheromone_model {
+            PheromoneModel::Point => self.points.iter()
+                .skip(1)
+                .map(|point| point.pheromone)
+                .collect(),
+            PheromoneModel::Edge => self.edges.iter()
+                .enumerate()
+                .filter(|&(index, _)| index / self.num_of_points != index % self.num_of_points)
+                .map(|(_, &pheromone)| pheromone)
+                .collect()
+            };
+        let total: f64 = strengths.iter().sum();
+
+        if total <= 0.0 || strengths.len() < 2 {
+            return None;
+            }
+
+        /* Sum the entropy of the non-empty trails */
+        let entropy: f64 = strengths.iter()
+            .filter(|&&strength| strength > 0.0)
+            .map(|strength| strength / total)
+            .map(|share| - share * share.ln())
+            .sum();
+
+        Some(entropy / (strengths.len() as f64).ln())
+        }
+    /** Grid the world was created from, with points' initial food. */
+    pub fn initial_grid(&self) -> Vec<Point> {
         self.points.iter()
-            .map(|point| point.pheromone)
+            .map(|point| {
+                let food_amount = self.initial_food_sources.get(&point.id)
+                    .copied()
+                    .unwrap_or_default();
+                Point::with_food(point.id, point.x, point.y, food_amount)
+                })
+            .collect()
+        }
+    /** `food_per_point` getter. */
+    pub fn food_per_point(&self) -> Box<[u32]> {
+        self.points.iter()
+            .map(|point| point.food_amount)
+            .collect()
+        }
+    /** `pheromones_per_edge` getter, holds only edges covered by the ants during the run, labeled with their points' IDs - empty for the point model. */
+    pub fn pheromones_per_edge(&self) -> BTreeMap<String, f64> {
+        let pairs = self.points.iter()
+            .flat_map(|from| self.points.iter().map(move |to| (from.id, to.id)));
+
+        zip(pairs, zip(&self.edges, &self.covered_edges))
+            .filter(|&(_, (_, &covered))| covered)
+            .map(|((from, to), (&pheromone, _))| (format!("{from}{to}"), pheromone))
             .collect()
         }
-    }
\ No newline at end of file
+    }
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::consts::default::GRID
+        };
+
+    /* Builds the route from the points' IDs */
+    fn route(ids: &str) -> Route {
+        let mut route = Route::new();
+        for id in ids.chars() {
+            route.push(Id::new(id));
+            }
+        route
+        }
+
+    /* Compares the floats up to the tolerance */
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!((actual - expected).abs() <= tolerance, "{actual} isn't close to {expected}");
+        }
+
+    #[test]
+    fn acs_local_update_moves_last_trail_towards_initial() {
+        let config = Config {
+            algorithm: Algorithm::Acs,
+            pheromone_model: PheromoneModel::Edge,
+            tau0: 0.5,
+            xi: 0.1,
+            ..Config::default()
+            };
+        let mut world = World::new(Vec::from(GRID), &config);
+        let (a, c, d) = (Id::new('a'), Id::new('c'), Id::new('d'));
+
+        world.cover_route(&route("acd"), &[], 1.0);
+        world.local_update(&route("acd"));
+
+        /* Only the last trail is updated, (1 - 0.1) * 1.5 + 0.1 * 0.5 */
+        assert_close(world.edges[world.edge_index(c, d)], 1.4, 1e-12);
+        assert_close(world.edges[world.edge_index(a, c)], 1.5, 1e-12);
+        assert_close(world.edges[world.edge_index(d, c)], 0.5, 1e-12);
+        }
+
+    #[test]
+    fn local_update_only_in_acs() {
+        let config = Config {
+            pheromone_model: PheromoneModel::Edge,
+            ..Config::default()
+            };
+        let mut world = World::new(Vec::from(GRID), &config);
+
+        world.cover_route(&route("ac"), &[], 1.0);
+        world.local_update(&route("ac"));
+
+        assert_close(world.edges[world.edge_index(Id::new('a'), Id::new('c'))], 1.0, 1e-12);
+        }
+
+    #[test]
+    fn mmas_trails_clamped_to_limits() {
+        let config = Config {
+            algorithm: Algorithm::Mmas,
+            tau_min: 0.1,
+            tau_max: 2.0,
+            dispersion: Some(Dispersion::Linear),
+            factor: 1.95,
+            ..Config::default()
+            };
+        let mut world = World::new(Vec::from(GRID), &config);
+
+        /* The covered point overflows the upper limit, the rest fall below the lower one */
+        world.cover_route(&route("ac"), &[], 5.0);
+        world.disperse_pheromons();
+
+        assert_eq!(world.trail_limits, (0.1, 2.0));
+        assert_close(world.find_point(Id::new('c')).pheromone, 2.0, 1e-12);
+        assert_close(world.find_point(Id::new('d')).pheromone, 0.1, 1e-12);
+        assert!(world.pheromones_per_point().iter().all(|pheromone| (0.1 ..= 2.0).contains(pheromone)));
+        }
+    }